@@ -0,0 +1,70 @@
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use sje::scanner::JsonScanner;
+
+/// Depth snapshot sized like a full REST order book.
+fn snapshot(levels: usize) -> Vec<u8> {
+    let bids = (0..levels)
+        .map(|i| format!(r#"["{}.{:08}","{}.00000000"]"#, 46000 - i, i, i + 1))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("[{}]", bids).into_bytes()
+}
+
+/// Byte by byte scan the structural scanner replaced, kept as the baseline.
+fn byte_loop(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut commas = 0usize;
+    for (index, &b) in bytes.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((index + 1, commas));
+                }
+            }
+            b',' if depth == 1 => commas += 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn scanner_benchmark(c: &mut Criterion) {
+    let json = snapshot(1000);
+    let mut group = c.benchmark_group("scanner");
+    group.throughput(Throughput::Bytes(json.len() as u64));
+
+    group.bench_function("next_array", |b| {
+        b.iter(|| {
+            let mut scanner = JsonScanner::wrap(black_box(&json));
+            let (_, len, count) = scanner.next_array().unwrap();
+            assert_eq!(json.len(), len);
+            assert_eq!(1000, count);
+        })
+    });
+
+    group.bench_function("byte_loop", |b| {
+        b.iter(|| {
+            let (len, commas) = byte_loop(black_box(&json)).unwrap();
+            assert_eq!(json.len(), len);
+            assert_eq!(999, commas);
+        })
+    });
+}
+
+criterion_group!(benches, scanner_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,178 @@
+use crate::error::Error;
+use std::fmt::{Display, Write};
+
+/// Destination for the encoded json bytes.
+pub trait Buffer {
+    fn put(&mut self, bytes: &[u8]) -> Result<(), Error>;
+}
+
+impl Buffer for Vec<u8> {
+    #[inline]
+    fn put(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Fixed size buffer backed by caller provided slice.
+pub struct SliceBuffer<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceBuffer<'a> {
+    #[inline]
+    pub const fn wrap(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Buffer for SliceBuffer<'_> {
+    #[inline]
+    fn put(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let end = self.position + bytes.len();
+        let dst = self
+            .bytes
+            .get_mut(self.position..end)
+            .ok_or(Error::BufferOverflow(end))?;
+        dst.copy_from_slice(bytes);
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// Implemented by the `Encoder` derive, writes compact json representation of the value.
+pub trait Encode {
+    fn encode<B: Buffer>(&self, buffer: &mut B) -> Result<(), Error>;
+}
+
+/// Write value as is, used for json numbers and booleans. Non-finite floats have no json representation and are
+/// rejected with an error.
+#[inline]
+pub fn write_value<B: Buffer, T: Display + ?Sized>(buffer: &mut B, value: &T) -> Result<(), Error> {
+    write_display(buffer, value, false)
+}
+
+/// Write value as json string, escaping any characters that are not allowed inside the quotes.
+#[inline]
+pub fn write_quoted<B: Buffer, T: Display + ?Sized>(buffer: &mut B, value: &T) -> Result<(), Error> {
+    buffer.put(b"\"")?;
+    write_display(buffer, value, true)?;
+    buffer.put(b"\"")
+}
+
+fn write_display<B: Buffer, T: Display + ?Sized>(buffer: &mut B, value: &T, escape: bool) -> Result<(), Error> {
+    let mut adapter = Adapter {
+        buffer,
+        escape,
+        error: None,
+    };
+    match write!(adapter, "{}", value) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(adapter.error.unwrap_or_else(|| Error::other("unable to format value"))),
+    }
+}
+
+struct Adapter<'a, B> {
+    buffer: &'a mut B,
+    escape: bool,
+    error: Option<Error>,
+}
+
+impl<B: Buffer> Adapter<'_, B> {
+    #[inline]
+    fn put(&mut self, bytes: &[u8]) -> std::fmt::Result {
+        self.buffer.put(bytes).map_err(|err| {
+            self.error = Some(err);
+            std::fmt::Error
+        })
+    }
+}
+
+impl<B: Buffer> Write for Adapter<'_, B> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if !self.escape {
+            // float `Display` writes the non-finite values as a separate chunk, after the sign
+            if matches!(s, "NaN" | "inf") {
+                self.error = Some(Error::other(format!("{} is not a valid json number", s)));
+                return Err(std::fmt::Error);
+            }
+            return self.put(s.as_bytes());
+        }
+        let bytes = s.as_bytes();
+        let mut start = 0;
+        for (index, &b) in bytes.iter().enumerate() {
+            let escaped: &[u8] = match b {
+                b'"' => b"\\\"",
+                b'\\' => b"\\\\",
+                b'\n' => b"\\n",
+                b'\r' => b"\\r",
+                b'\t' => b"\\t",
+                0x08 => b"\\b",
+                0x0c => b"\\f",
+                0x00..=0x1f => {
+                    const HEX: &[u8; 16] = b"0123456789abcdef";
+                    self.put(&bytes[start..index])?;
+                    self.put(&[b'\\', b'u', b'0', b'0', HEX[(b >> 4) as usize], HEX[(b & 0xf) as usize]])?;
+                    start = index + 1;
+                    continue;
+                }
+                _ => continue,
+            };
+            self.put(&bytes[start..index])?;
+            self.put(escaped)?;
+            start = index + 1;
+        }
+        self.put(&bytes[start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_values() {
+        let mut buffer = Vec::new();
+        write_value(&mut buffer, &123u64).unwrap();
+        buffer.put(b",").unwrap();
+        write_value(&mut buffer, &true).unwrap();
+        buffer.put(b",").unwrap();
+        write_quoted(&mut buffer, "BTCUSDT").unwrap();
+        assert_eq!(br#"123,true,"BTCUSDT""#, buffer.as_slice());
+    }
+
+    #[test]
+    fn should_reject_non_finite_floats() {
+        let mut buffer = Vec::new();
+        assert!(matches!(write_value(&mut buffer, &f64::NAN), Err(Error::Other(_))));
+        assert!(matches!(write_value(&mut buffer, &f64::INFINITY), Err(Error::Other(_))));
+        assert!(matches!(write_value(&mut buffer, &f32::NEG_INFINITY), Err(Error::Other(_))));
+
+        let mut buffer = Vec::new();
+        write_value(&mut buffer, &-1.5f64).unwrap();
+        write_quoted(&mut buffer, &f64::NAN).unwrap();
+        assert_eq!(br#"-1.5"NaN""#, buffer.as_slice());
+    }
+
+    #[test]
+    fn should_escape_quoted_values() {
+        let mut buffer = Vec::new();
+        write_quoted(&mut buffer, "a\"b\\c\nd\u{1}").unwrap();
+        assert_eq!(br#""a\"b\\c\nd\u0001""#, buffer.as_slice());
+    }
+
+    #[test]
+    fn should_fail_when_slice_is_too_small() {
+        let mut bytes = [0u8; 4];
+        let mut buffer = SliceBuffer::wrap(&mut bytes);
+        write_value(&mut buffer, &1234u64).unwrap();
+        assert_eq!(4, buffer.position());
+        assert!(matches!(write_value(&mut buffer, &5u64), Err(Error::BufferOverflow(5))));
+    }
+}
@@ -6,6 +6,12 @@ pub enum Error {
     Parse(String),
     #[error("missing field `{0}`")]
     MissingField(&'static str),
+    #[error("unexpected key, expected `{expected}` but found `{found}`")]
+    UnexpectedKey { expected: &'static str, found: String },
+    #[error("unknown variant `{0}`")]
+    UnknownVariant(String),
+    #[error("buffer overflow, required {0} bytes")]
+    BufferOverflow(usize),
     #[error("other error {0}")]
     Other(String),
 }
@@ -14,6 +20,21 @@ impl Error {
     pub fn other(msg: impl AsRef<str>) -> Self {
         Error::Other(msg.as_ref().to_string())
     }
+
+    #[cold]
+    pub fn unexpected_key(expected: &'static str, found: Option<&[u8]>) -> Self {
+        Error::UnexpectedKey {
+            expected,
+            found: found
+                .map(|key| String::from_utf8_lossy(key).into_owned())
+                .unwrap_or_default(),
+        }
+    }
+
+    #[cold]
+    pub fn unknown_variant(found: &[u8]) -> Self {
+        Error::UnknownVariant(String::from_utf8_lossy(found).into_owned())
+    }
 }
 
 impl From<Error> for std::io::Error {
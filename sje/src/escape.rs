@@ -0,0 +1,115 @@
+use crate::error::Error;
+use std::borrow::Cow;
+use std::str::{from_utf8, from_utf8_unchecked};
+
+/// Decode escape sequences of the json string value (without the quotes). The value is borrowed when no escape
+/// sequences are present, bytes that are not valid utf-8 are rejected with [`Error::Parse`].
+#[inline]
+pub fn unescape(bytes: &[u8]) -> Result<Cow<'_, str>, Error> {
+    let value = from_utf8(bytes).map_err(|_| Error::Parse(String::from_utf8_lossy(bytes).into_owned()))?;
+    match memchr::memchr(b'\\', bytes) {
+        None => Ok(Cow::Borrowed(value)),
+        Some(index) => unescape_from(bytes, index).map(Cow::Owned),
+    }
+}
+
+/// The `bytes` must be valid utf-8, the chunks between the escape sequences are split at ascii backslashes so they
+/// remain valid too.
+#[cold]
+fn unescape_from(bytes: &[u8], mut index: usize) -> Result<String, Error> {
+    let invalid = || Error::Parse(String::from_utf8_lossy(bytes).into_owned());
+    let mut unescaped = String::with_capacity(bytes.len());
+    let mut start = 0;
+    loop {
+        unescaped.push_str(unsafe { from_utf8_unchecked(&bytes[start..index]) });
+        let (ch, len) = match bytes.get(index + 1).ok_or_else(invalid)? {
+            b'"' => ('"', 2),
+            b'\\' => ('\\', 2),
+            b'/' => ('/', 2),
+            b'b' => ('\x08', 2),
+            b'f' => ('\x0c', 2),
+            b'n' => ('\n', 2),
+            b'r' => ('\r', 2),
+            b't' => ('\t', 2),
+            b'u' => {
+                let high = hex4(bytes, index + 2).ok_or_else(invalid)?;
+                match high {
+                    // surrogate pair must be followed by the low surrogate
+                    0xd800..=0xdbff => {
+                        if bytes.get(index + 6..index + 8) != Some(b"\\u") {
+                            return Err(invalid());
+                        }
+                        let low = hex4(bytes, index + 8).ok_or_else(invalid)?;
+                        if !(0xdc00..=0xdfff).contains(&low) {
+                            return Err(invalid());
+                        }
+                        let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                        (char::from_u32(code).ok_or_else(invalid)?, 12)
+                    }
+                    _ => (char::from_u32(high).ok_or_else(invalid)?, 6),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+        unescaped.push(ch);
+        start = index + len;
+        match memchr::memchr(b'\\', &bytes[start..]) {
+            Some(next) => index = start + next,
+            None => break,
+        }
+    }
+    unescaped.push_str(unsafe { from_utf8_unchecked(&bytes[start..]) });
+    Ok(unescaped)
+}
+
+#[inline]
+fn hex4(bytes: &[u8], offset: usize) -> Option<u32> {
+    let digits = bytes.get(offset..offset + 4)?;
+    digits
+        .iter()
+        .try_fold(0u32, |acc, &b| Some((acc << 4) | (b as char).to_digit(16)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_borrow_when_not_escaped() {
+        let unescaped = unescape(b"BTCUSDT").unwrap();
+        assert!(matches!(unescaped, Cow::Borrowed("BTCUSDT")));
+    }
+
+    #[test]
+    fn should_unescape() {
+        let unescaped = unescape(br#"a\"b\\c\/d\be\ff\ng\rh\ti"#).unwrap();
+        assert!(matches!(unescaped, Cow::Owned(_)));
+        assert_eq!("a\"b\\c/d\x08e\x0cf\ng\rh\ti", unescaped);
+    }
+
+    #[test]
+    fn should_unescape_unicode() {
+        assert_eq!("caf\u{e9}", unescape(br#"caf\u00e9"#).unwrap());
+        assert_eq!("\"\u{e9}\"", unescape(br#"\u0022\u00E9\""#).unwrap());
+        assert_eq!("\u{1f600}!", unescape(br#"\ud83d\ude00!"#).unwrap());
+        assert_eq!("\u{fc}\u{1f600}", unescape("\u{fc}\\ud83d\\ude00".as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn should_reject_invalid_escapes() {
+        assert!(matches!(unescape(br#"abc\"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\x"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\u00g1"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\u00e"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\ud83d"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\ud83dA"#), Err(Error::Parse(_))));
+        assert!(matches!(unescape(br#"\ude00"#), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn should_reject_invalid_utf8() {
+        assert!(matches!(unescape(&[b'a', 0xff]), Err(Error::Parse(_))));
+        assert!(matches!(unescape(&[0xc3, b'\\', b'n']), Err(Error::Parse(_))));
+        assert!(matches!(unescape(&[b'\\', b'n', 0xe9]), Err(Error::Parse(_))));
+    }
+}
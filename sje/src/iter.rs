@@ -0,0 +1,72 @@
+use crate::error::Error;
+
+/// Iterator over the elements of a fallible iterator that stops at the first error and keeps it, so the elements
+/// can be consumed with a plain `for` loop and the error checked afterwards.
+pub struct ErrorRecorder<I> {
+    iter: I,
+    error: Option<Error>,
+    stopped: bool,
+}
+
+impl<I> ErrorRecorder<I> {
+    #[inline]
+    pub const fn new(iter: I) -> Self {
+        Self {
+            iter,
+            error: None,
+            stopped: false,
+        }
+    }
+
+    /// First error encountered, if any.
+    #[inline]
+    pub const fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Take the first error encountered, if any. The iterator remains stopped after the error has been taken.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+}
+
+impl<I, T> Iterator for ErrorRecorder<I>
+where
+    I: Iterator<Item = Result<T, Error>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(item) => Some(item),
+            Err(err) => {
+                self.error = Some(err);
+                self.stopped = true;
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_stop_at_first_error() {
+        let items = vec![Ok(1), Err(Error::other("boom")), Ok(3)];
+        let mut iter = ErrorRecorder::new(items.into_iter());
+        assert_eq!(vec![1], iter.by_ref().collect::<Vec<_>>());
+        assert!(matches!(iter.error(), Some(Error::Other(_))));
+        assert_eq!(None, iter.next());
+        assert!(iter.take_error().is_some());
+        assert!(iter.error().is_none());
+        assert_eq!(None, iter.next());
+        assert!(iter.error().is_none());
+    }
+}
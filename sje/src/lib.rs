@@ -2,12 +2,19 @@ use crate::error::Error;
 use std::cell::UnsafeCell;
 use std::str::{FromStr, from_utf8_unchecked};
 
+pub mod encoder;
 pub mod error;
+mod escape;
+mod iter;
 mod macros;
 pub mod scanner;
+mod structural;
+
+pub use escape::unescape;
+pub use iter::ErrorRecorder;
 
 #[cfg(feature = "derive")]
-pub use sje_derive::Decoder;
+pub use sje_derive::{Decoder, Encoder};
 
 #[derive(Debug)]
 pub struct LazyField<'a, T> {
@@ -69,7 +76,7 @@ impl<'a, T> LazyField<'a, T> {
     }
 
     #[inline]
-    pub const fn as_slice(&self) -> &[u8] {
+    pub const fn as_slice(&self) -> &'a [u8] {
         // SAFETY: We use UnsafeCell to gain mutable access.
         // It is up to you to ensure that this mutation is safe (e.g., no concurrent
         // accesses) and that T's invariants are upheld.
@@ -83,7 +90,7 @@ impl<'a, T> LazyField<'a, T> {
     }
 
     #[inline]
-    pub const fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &'a str {
         unsafe { from_utf8_unchecked(self.as_slice()) }
     }
 }
@@ -5,7 +5,12 @@ macro_rules! field_impl1 {
             #[inline]
             pub fn $method_name(&mut self) -> Option<(usize, usize)> {
                 let offset = self.cursor + $quoted;
-                let len = memchr::memchr($one, unsafe { self.bytes.get_unchecked(offset..) })?;
+                let bytes = $crate::scanner::tail(self.bytes, offset)?;
+                let mut len = memchr::memchr($one, bytes)?;
+                // keep searching past any escaped terminator
+                while $crate::scanner::is_escaped(bytes, len) {
+                    len += 1 + memchr::memchr($one, unsafe { bytes.get_unchecked(len + 1..) })?;
+                }
                 self.cursor += len + $quoted * 2;
                 Some((offset, len))
             }
@@ -13,6 +18,9 @@ macro_rules! field_impl1 {
             #[inline]
             pub const fn $method_name_with_known_len(&mut self, len: usize) -> Option<(usize, usize)> {
                 let offset = self.cursor + $quoted;
+                if cfg!(feature = "checked") && offset + len + $quoted > self.bytes.len() {
+                    return None;
+                }
                 self.cursor += len + $quoted * 2;
                 Some((offset, len))
             }
@@ -27,7 +35,7 @@ macro_rules! field_impl3 {
             #[inline]
             pub fn $method_name(&mut self) -> Option<(usize, usize)> {
                 let offset = self.cursor + $quoted;
-                let len = memchr::memchr3($one, $two, $three, unsafe { self.bytes.get_unchecked(offset..) })?;
+                let len = memchr::memchr3($one, $two, $three, $crate::scanner::tail(self.bytes, offset)?)?;
                 self.cursor += len + $quoted * 2;
                 Some((offset, len))
             }
@@ -35,6 +43,9 @@ macro_rules! field_impl3 {
             #[inline]
             pub const fn $method_name_with_known_len(&mut self, len: usize) -> Option<(usize, usize)> {
                 let offset = self.cursor + $quoted;
+                if cfg!(feature = "checked") && offset + len + $quoted > self.bytes.len() {
+                    return None;
+                }
                 self.cursor += len + $quoted * 2;
                 Some((offset, len))
             }
@@ -44,40 +55,19 @@ macro_rules! field_impl3 {
 
 #[macro_export]
 macro_rules! composite_impl {
-    ($method_name:ident, $open_char:literal, $close_char:literal) => {
+    ($method_name:ident, $open_char:literal) => {
         impl<'a> JsonScanner<'a> {
+            /// Returns the span of the value opening at the current position, `None` when it does not start with
+            /// the expected bracket. Not `const` as the scan picks the SIMD implementation at runtime.
             #[inline]
-            pub const fn $method_name(&mut self) -> Option<(usize, usize)> {
-                let bytes = self.bytes;
+            pub fn $method_name(&mut self) -> Option<(usize, usize)> {
                 let start = self.cursor;
-                let mut counter: u32 = 1;
-                let mut i: usize = 0;
-
-                loop {
-                    // if we've run off the end, give up
-                    let idx = start + 1 + i;
-                    if idx >= bytes.len() {
-                        return None;
-                    }
-
-                    // fetch the next byte after the opening char
-                    let b = bytes[idx];
-
-                    // bump the nesting counter
-                    match b {
-                        $open_char => counter += 1,
-                        $close_char => counter -= 1,
-                        _ => {}
-                    }
-
-                    // if we've closed the top‐level object, return its span
-                    if counter == 0 {
-                        self.cursor = start + i + 2;
-                        return Some((start, i + 2));
-                    }
-
-                    i += 1;
+                if self.bytes.get(start) != Some(&$open_char) {
+                    return None;
                 }
+                let (len, _) = $crate::structural::scan(self.bytes, start)?;
+                self.cursor = start + len;
+                Some((start, len))
             }
         }
     };
@@ -1,4 +1,15 @@
-use crate::{composite_impl, field_impl1, field_impl3};
+use crate::{composite_impl, field_impl1, field_impl3, structural};
+
+/// Type of the json value as detected by [`JsonScanner::next_value`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum JsonKind {
+    String,
+    Number,
+    Boolean,
+    Null,
+    Object,
+    Array,
+}
 
 pub struct JsonScanner<'a> {
     bytes: &'a [u8],
@@ -25,115 +36,208 @@ impl<'a> JsonScanner<'a> {
     pub const fn bytes(&self) -> &[u8] {
         self.bytes
     }
-}
 
-field_impl1!(next_string, next_string_with_known_len 1, b'"');
-field_impl3!(next_number, next_number_with_known_len, 0, b',', b']', b'}');
-field_impl3!(next_boolean, next_boolean_with_known_len, 0, b',', b']', b'}');
-composite_impl!(next_tuple, b'[', b']');
-composite_impl!(next_object, b'{', b'}');
+    /// Check if the quoted key (including the colon) follows the separator at the current position.
+    #[inline]
+    pub fn matches_key(&self, key: &[u8]) -> bool {
+        match self.bytes.get(self.cursor + 1..self.cursor + 1 + key.len()) {
+            Some(bytes) => bytes == key,
+            None => false,
+        }
+    }
 
-impl JsonScanner<'_> {
-    pub const fn next_array(&mut self) -> Option<(usize, usize, usize)> {
-        let bytes = self.bytes;
-        let start = self.cursor;
+    /// Key (without the quotes) that follows the separator at the current position.
+    #[cold]
+    pub fn peek_key(&self) -> Option<&'a [u8]> {
+        if self.bytes.get(self.cursor + 1) != Some(&b'"') {
+            return None;
+        }
+        let offset = self.cursor + 2;
+        let len = memchr::memchr(b'"', self.bytes.get(offset..)?)?;
+        Some(&self.bytes[offset..offset + len])
+    }
+
+    /// Check if the value at the current position is `null`.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self.bytes.get(self.cursor..self.cursor + 4), Some(b"null"))
+    }
 
-        // state
-        let mut array_depth = 0usize;
-        let mut obj_depth = 0usize;
-        let mut in_string = false;
-        let mut escaped = false;
-        let mut commas = 0usize;
-        let mut saw_value = false;
-        let mut index = 0usize;
+    /// Check if only the closing brace of the object remains, ignoring any whitespace before it.
+    #[inline]
+    pub fn is_object_end(&self) -> bool {
+        let rest = self.bytes.get(self.cursor..).unwrap_or_default();
+        matches!(rest.iter().find(|b| !b.is_ascii_whitespace()), Some(b'}'))
+    }
 
-        // iterate until we run off the end
-        loop {
-            // bounds-check
-            if start + index >= bytes.len() {
-                return None;
+    /// Advance past the `null` value at the current position.
+    #[inline]
+    pub fn next_null(&mut self) -> Option<(usize, usize)> {
+        if !self.is_null() {
+            return None;
+        }
+        let offset = self.cursor;
+        self.cursor += 4;
+        Some((offset, 4))
+    }
+
+    /// Advance past the separator (`{` or `,`), the key and the colon, returning the key span without the quotes.
+    /// The cursor is left at the start of the value. Returns `None` once the end of the object has been reached.
+    pub fn next_key(&mut self) -> Option<(usize, usize)> {
+        self.skip_whitespace();
+        match self.bytes.get(self.cursor)? {
+            b'{' | b',' => self.cursor += 1,
+            _ => return None,
+        }
+        self.skip_whitespace();
+        if *self.bytes.get(self.cursor)? != b'"' {
+            return None;
+        }
+        let (offset, len) = self.next_string()?;
+        self.skip_whitespace();
+        if *self.bytes.get(self.cursor)? != b':' {
+            return None;
+        }
+        self.cursor += 1;
+        self.skip_whitespace();
+        Some((offset, len))
+    }
+
+    /// Advance past the key only if it matches the given key (without the quotes), otherwise the cursor is left as is.
+    #[inline]
+    pub fn next_key_if(&mut self, key: &[u8]) -> bool {
+        let cursor = self.cursor;
+        match self.next_key() {
+            Some((offset, len)) if &self.bytes[offset..offset + len] == key => true,
+            _ => {
+                self.cursor = cursor;
+                false
             }
-            let b = bytes[start + index];
-
-            // 1) handle strings & escapes
-            if in_string {
-                if escaped {
-                    escaped = false;
-                } else if b == b'\\' {
-                    escaped = true;
-                } else if b == b'"' {
-                    in_string = false;
-                }
-                index += 1;
-                continue;
-            } else if b == b'"' {
-                in_string = true;
-                index += 1;
-                continue;
+        }
+    }
+
+    /// Advance past the separator (`[` or `,`) that precedes the array element and any whitespace around it.
+    /// Returns `None` once the end of the array has been reached.
+    #[inline]
+    pub fn next_element(&mut self) -> Option<()> {
+        self.skip_whitespace();
+        match self.bytes.get(self.cursor)? {
+            b'[' | b',' => self.cursor += 1,
+            _ => return None,
+        }
+        self.skip_whitespace();
+        Some(())
+    }
+
+    /// Span without the trailing whitespace, numbers and booleans end at the separator so they would include it.
+    #[inline]
+    pub fn trim_end(&self, offset: usize, mut len: usize) -> (usize, usize) {
+        while len > 0 && self.bytes[offset + len - 1].is_ascii_whitespace() {
+            len -= 1;
+        }
+        (offset, len)
+    }
+
+    /// Advance to the value of the given key (without the quotes), skipping any other keys and their values.
+    /// The cursor is left at the start of the value.
+    #[cold]
+    pub fn seek_key(&mut self, key: &[u8]) -> Option<()> {
+        loop {
+            let (offset, len) = self.next_key()?;
+            if &self.bytes[offset..offset + len] == key {
+                return Some(());
             }
+            self.skip_value()?;
+        }
+    }
+
+    /// Advance past the value at the current position, detecting its type from the first byte. The string span
+    /// excludes the quotes, same as with [`Self::next_string`].
+    pub fn next_value(&mut self) -> Option<(usize, usize, JsonKind)> {
+        let (kind, (offset, len)) = match self.bytes.get(self.cursor)? {
+            b'"' => (JsonKind::String, self.next_string()?),
+            b'{' => (JsonKind::Object, self.next_object()?),
+            b'[' => (JsonKind::Array, self.next_tuple()?),
+            b't' | b'f' => (JsonKind::Boolean, self.next_boolean()?),
+            b'n' => (JsonKind::Null, self.next_null()?),
+            b'-' | b'0'..=b'9' => (JsonKind::Number, self.next_number()?),
+            _ => return None,
+        };
+        Some((offset, len, kind))
+    }
+
+    /// Advance past the value at the current position without knowing its type up front.
+    #[inline]
+    pub fn skip_value(&mut self) -> Option<(usize, usize)> {
+        self.next_value().map(|(offset, len, _)| (offset, len))
+    }
 
-            // 2) track nesting and detect top-level elements
-            match b {
-                // entering any array
-                b'[' => {
-                    array_depth += 1;
-                    if array_depth == 1 {
-                        // this is the '[' of *our* array
-                        saw_value = false;
-                    } else if array_depth == 2 {
-                        // nested array => counts as an element
-                        saw_value = true;
-                    }
-                }
-
-                // leaving an array
-                b']' => {
-                    array_depth -= 1;
-                    if array_depth == 0 {
-                        // done with this array
-                        let element_count = if saw_value { commas + 1 } else { 0 };
-                        // advance cursor past the closing ]
-                        self.cursor = start + index + 1;
-                        return Some((start, index + 1, element_count));
-                    }
-                }
-
-                // entering an object (only matters inside our array)
-                b'{' if array_depth > 0 => {
-                    if array_depth == 1 && obj_depth == 0 {
-                        // top-level object => counts as element
-                        saw_value = true;
-                    }
-                    obj_depth += 1;
-                }
-
-                // leaving an object
-                b'}' if array_depth > 0 => {
-                    obj_depth -= 1;
-                }
-
-                // a comma that really separates two top-level elements
-                b',' if array_depth == 1 && obj_depth == 0 => {
-                    commas += 1;
-                    saw_value = false; // now look for next element
-                }
-
-                // any other non-whitespace at top-level marks “we saw a value”
-                _ if array_depth == 1 && !b.is_ascii_whitespace() => {
-                    saw_value = true;
-                }
-
-                _ => {}
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        while let Some(b) = self.bytes.get(self.cursor) {
+            if !b.is_ascii_whitespace() {
+                break;
             }
+            self.cursor += 1;
+        }
+    }
+}
 
-            index += 1;
+/// Remaining bytes from the `offset`, with the `checked` feature an offset past the end returns `None` instead of
+/// being undefined behaviour.
+#[inline]
+pub(crate) fn tail(bytes: &[u8], offset: usize) -> Option<&[u8]> {
+    match cfg!(feature = "checked") {
+        true => bytes.get(offset..),
+        false => Some(unsafe { bytes.get_unchecked(offset..) }),
+    }
+}
+
+/// Check if the byte at `index` is escaped, that is preceded by an odd number of backslashes.
+#[inline]
+pub(crate) const fn is_escaped(bytes: &[u8], index: usize) -> bool {
+    // fast path, the terminator is almost never preceded by a backslash
+    if index == 0 || bytes[index - 1] != b'\\' {
+        return false;
+    }
+    let mut count = 1;
+    while count < index && bytes[index - 1 - count] == b'\\' {
+        count += 1;
+    }
+    count % 2 == 1
+}
+
+field_impl1!(next_string, next_string_with_known_len 1, b'"');
+field_impl3!(next_number, next_number_with_known_len, 0, b',', b']', b'}');
+field_impl3!(next_boolean, next_boolean_with_known_len, 0, b',', b']', b'}');
+composite_impl!(next_tuple, b'[');
+composite_impl!(next_object, b'{');
+
+impl JsonScanner<'_> {
+    /// Returns the array span and the number of elements. Not `const` as the scan picks the SIMD implementation at
+    /// runtime.
+    #[inline]
+    pub fn next_array(&mut self) -> Option<(usize, usize, usize)> {
+        let start = self.cursor;
+        // any whitespace before the opening bracket is included in the span
+        let open = start + self.bytes.get(start..)?.iter().position(|b| !b.is_ascii_whitespace())?;
+        if self.bytes[open] != b'[' {
+            return None;
         }
+        let (len, commas) = structural::scan(self.bytes, open)?;
+        // no separators is either an empty array or a single element
+        let count = match self.bytes[open + 1..].iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b']') => 0,
+            _ => commas + 1,
+        };
+        self.cursor = open + len;
+        Some((start, open + len - start, count))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::scanner::JsonScanner;
+    use crate::scanner::{JsonKind, JsonScanner};
 
     #[test]
     fn should_scan_strings_and_numbers() {
@@ -299,6 +403,26 @@ mod tests {
         assert_eq!(r#"{"id":{}}"#.as_bytes(), &bytes[offset..offset + len]);
     }
 
+    #[test]
+    fn should_reject_unexpected_opening_bracket() {
+        let bytes = br#"{"b":[1,2],"c":{"id":1},"d":null}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        assert_eq!(None, scanner.next_object());
+        let (offset, len) = scanner.next_tuple().unwrap();
+        assert_eq!("[1,2]".as_bytes(), &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        assert_eq!(None, scanner.next_tuple());
+        let (offset, len) = scanner.next_object().unwrap();
+        assert_eq!(r#"{"id":1}"#.as_bytes(), &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        assert_eq!(None, scanner.next_object());
+        assert_eq!(None, scanner.next_tuple());
+    }
+
     #[test]
     fn should_scan_boolean() {
         let bytes = br#"{"b":false,"c":true}}"#;
@@ -331,6 +455,250 @@ mod tests {
         assert_eq!("-541.56".as_bytes(), &bytes[offset..offset + len]);
     }
 
+    #[test]
+    fn should_match_keys() {
+        let bytes = br#"{"e":"trade","E":1705085312569}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        assert!(scanner.matches_key(br#""e":"#));
+        assert!(!scanner.matches_key(br#""E":"#));
+        assert_eq!(Some(b"e".as_slice()), scanner.peek_key());
+
+        scanner.skip(5);
+        scanner.next_string().unwrap();
+        assert!(scanner.matches_key(br#""E":"#));
+        assert_eq!(Some(b"E".as_slice()), scanner.peek_key());
+
+        scanner.skip(5);
+        scanner.next_number().unwrap();
+        assert!(!scanner.matches_key(br#""T":"#));
+        assert_eq!(None, scanner.peek_key());
+    }
+
+    #[test]
+    fn should_scan_keys_and_skip_values() {
+        let bytes = br#"{"e":"trade", "o" : {"x":{}}, "a":[1,[2]],"m":true}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        let mut keys = Vec::new();
+        while let Some((offset, len)) = scanner.next_key() {
+            keys.push(&bytes[offset..offset + len]);
+            scanner.skip_value().unwrap();
+        }
+        assert_eq!(vec![b"e".as_slice(), b"o", b"a", b"m"], keys);
+    }
+
+    #[test]
+    fn should_scan_strings_with_escaped_quotes() {
+        let bytes = br#"{"a":"a\"b","b":"\"","c":"x\"y\"z","d":1}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"a\"b"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"\""#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"x\"y\"z"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_number().unwrap();
+        assert_eq!(b"1", &bytes[offset..offset + len]);
+    }
+
+    #[test]
+    fn should_scan_strings_with_escaped_backslashes() {
+        let bytes = br#"{"a":"a\\","b":"\\\"\\","c":"\\\\"}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"a\\"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"\\\"\\"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"\\\\"#, &bytes[offset..offset + len]);
+        assert_eq!(bytes.len() - 1, scanner.position());
+    }
+
+    #[test]
+    fn should_scan_strings_with_unicode_escapes() {
+        let bytes = br#"{"a":"caf\u00e9","b":"\u0022\ud83d\ude00","c":"\\u0022"}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"caf\u00e9"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"\u0022\ud83d\ude00"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(br#"\\u0022"#, &bytes[offset..offset + len]);
+    }
+
+    #[test]
+    fn should_not_scan_unterminated_string() {
+        let mut scanner = JsonScanner::wrap(br#""abc\""#);
+        assert_eq!(None, scanner.next_string());
+    }
+
+    #[test]
+    fn should_scan_composites_with_brackets_in_strings() {
+        let bytes = br#"{"o":{"c":"x}y","d":"{"},"t":["]","a\"]",["["]],"e":{"c":"\\"}}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_object().unwrap();
+        assert_eq!(br#"{"c":"x}y","d":"{"}"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_tuple().unwrap();
+        assert_eq!(br#"["]","a\"]",["["]]"#, &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        let (offset, len) = scanner.next_object().unwrap();
+        assert_eq!(br#"{"c":"\\"}"#, &bytes[offset..offset + len]);
+        assert_eq!(bytes.len() - 1, scanner.position());
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    fn should_not_scan_past_the_end() {
+        let bytes = br#"{"e":"trade","E":1705085312569}"#;
+
+        let mut scanner = JsonScanner::wrap(bytes);
+        scanner.skip(64);
+        assert_eq!(None, scanner.next_string());
+        assert_eq!(None, scanner.next_number());
+        assert_eq!(None, scanner.next_boolean());
+
+        let mut scanner = JsonScanner::wrap(bytes);
+        scanner.skip(5);
+        assert_eq!(None, scanner.next_string_with_known_len(64));
+        assert_eq!(Some((6, 5)), scanner.next_string_with_known_len(5));
+        scanner.skip(5);
+        assert_eq!(None, scanner.next_number_with_known_len(15));
+        assert_eq!(Some((17, 13)), scanner.next_number_with_known_len(13));
+    }
+
+    #[test]
+    fn should_seek_key() {
+        let bytes = br#"{"E":1,"o":{"e":"x"},"e":"trade"}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+        scanner.seek_key(b"e").unwrap();
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(b"trade", &bytes[offset..offset + len]);
+
+        let mut scanner = JsonScanner::wrap(bytes);
+        assert_eq!(None, scanner.seek_key(b"x"));
+    }
+
+    #[test]
+    fn should_scan_with_whitespace() {
+        let bytes = b"{\n  \"a\" : 1 ,\n  \"b\": [ 2 , true\n ]\n}";
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        assert!(!scanner.next_key_if(b"b"));
+        assert!(scanner.next_key_if(b"a"));
+        let (offset, len) = scanner.next_number().unwrap();
+        assert_eq!(b"1 ", &bytes[offset..offset + len]);
+        let (offset, len) = scanner.trim_end(offset, len);
+        assert_eq!(b"1", &bytes[offset..offset + len]);
+
+        assert!(scanner.next_key_if(b"b"));
+        scanner.next_element().unwrap();
+        let (offset, len) = scanner.next_number().unwrap();
+        let (offset, len) = scanner.trim_end(offset, len);
+        assert_eq!(b"2", &bytes[offset..offset + len]);
+        scanner.next_element().unwrap();
+        let (offset, len) = scanner.next_boolean().unwrap();
+        let (offset, len) = scanner.trim_end(offset, len);
+        assert_eq!(b"true", &bytes[offset..offset + len]);
+        assert_eq!(None, scanner.next_element());
+    }
+
+    #[test]
+    fn should_scan_values_of_any_kind() {
+        let bytes = br#"{"s":"a,b","n":-1.5e3,"t":true,"f":false,"z":null,"o":{"x":[1]},"a":[{"y":2}]}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        let mut values = Vec::new();
+        while scanner.next_key().is_some() {
+            let (offset, len, kind) = scanner.next_value().unwrap();
+            values.push((kind, &bytes[offset..offset + len]));
+        }
+        assert_eq!(
+            vec![
+                (JsonKind::String, b"a,b".as_slice()),
+                (JsonKind::Number, b"-1.5e3"),
+                (JsonKind::Boolean, b"true"),
+                (JsonKind::Boolean, b"false"),
+                (JsonKind::Null, b"null"),
+                (JsonKind::Object, br#"{"x":[1]}"#),
+                (JsonKind::Array, br#"[{"y":2}]"#),
+            ],
+            values
+        );
+    }
+
+    #[test]
+    fn should_not_scan_invalid_value() {
+        let mut scanner = JsonScanner::wrap(b"}");
+        assert_eq!(None, scanner.next_value());
+        assert_eq!(None, scanner.skip_value());
+        assert_eq!(0, scanner.position());
+    }
+
+    #[test]
+    fn should_not_scan_keys_in_empty_object() {
+        let mut scanner = JsonScanner::wrap(b"{ }");
+        assert_eq!(None, scanner.next_key());
+    }
+
+    #[test]
+    fn should_scan_null() {
+        let bytes = br#"{"a":null,"b":"null","c":nul}"#;
+        let mut scanner = JsonScanner::wrap(bytes);
+
+        scanner.skip(5);
+        assert!(scanner.is_null());
+        let (offset, len) = scanner.next_null().unwrap();
+        assert_eq!(b"null", &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        assert!(!scanner.is_null());
+        assert_eq!(None, scanner.next_null());
+        let (offset, len) = scanner.next_string().unwrap();
+        assert_eq!(b"null", &bytes[offset..offset + len]);
+
+        scanner.skip(5);
+        assert!(!scanner.is_null());
+        assert_eq!(None, scanner.next_null());
+    }
+
+    #[test]
+    fn should_detect_object_end() {
+        let bytes = b"{\"a\":1 \n}";
+        let mut scanner = JsonScanner::wrap(bytes);
+        assert!(!scanner.is_object_end());
+        scanner.skip(5);
+        scanner.next_number().unwrap();
+        assert!(scanner.is_object_end());
+        scanner.skip(3);
+        assert!(!scanner.is_object_end());
+    }
+
     #[test]
     fn should_scan_array_of_objects() {
         let bytes = br#"[{"s":"btcusdt","a":100},{"s":"ethusdt","a":200}]"#;
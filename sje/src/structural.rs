@@ -0,0 +1,237 @@
+//! Structural scanning of the composite values. Quotes, backslashes, brackets and commas are classified in blocks
+//! of 16 (SSE2) or 32 (AVX2) bytes so that only those bytes are visited, the rest is skipped a block at a time.
+
+/// Scan the composite value opening at `start`, returning its length (including the brackets) and the number of
+/// commas that separate the top level elements. Both `[]` and `{}` count towards the same nesting depth.
+#[inline]
+pub(crate) fn scan(bytes: &[u8], start: usize) -> Option<(usize, usize)> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            return unsafe { x86::scan_avx2(bytes, start) };
+        }
+        // always available on x86_64
+        unsafe { x86::scan_sse2(bytes, start) }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        scan_scalar(bytes, start)
+    }
+}
+
+/// Byte by byte scan, also used for the tail that does not fill the whole block.
+#[inline]
+#[cfg_attr(target_arch = "x86_64", allow(dead_code))]
+pub(crate) fn scan_scalar(bytes: &[u8], start: usize) -> Option<(usize, usize)> {
+    let mut state = State::new(start);
+    state.visit_from(bytes, start + 1)
+}
+
+struct State {
+    start: usize,
+    depth: usize,
+    in_string: bool,
+    /// index of the first byte after the escape sequence
+    escaped_until: usize,
+    commas: usize,
+}
+
+impl State {
+    #[inline]
+    const fn new(start: usize) -> Self {
+        Self {
+            start,
+            depth: 1,
+            in_string: false,
+            escaped_until: 0,
+            commas: 0,
+        }
+    }
+
+    /// Returns length and commas once the closing bracket has been found.
+    #[inline]
+    fn visit(&mut self, index: usize, b: u8) -> Option<(usize, usize)> {
+        if index < self.escaped_until {
+            return None;
+        }
+        if self.in_string {
+            match b {
+                b'\\' => self.escaped_until = index + 2,
+                b'"' => self.in_string = false,
+                _ => {}
+            }
+            return None;
+        }
+        match b {
+            b'"' => self.in_string = true,
+            b'[' | b'{' => self.depth += 1,
+            b']' | b'}' => {
+                self.depth -= 1;
+                if self.depth == 0 {
+                    return Some((index + 1 - self.start, self.commas));
+                }
+            }
+            b',' if self.depth == 1 => self.commas += 1,
+            _ => {}
+        }
+        None
+    }
+
+    #[inline]
+    fn visit_from(&mut self, bytes: &[u8], offset: usize) -> Option<(usize, usize)> {
+        for (index, &b) in bytes.get(offset..)?.iter().enumerate() {
+            if let Some(found) = self.visit(offset + index, b) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// Visit the bytes marked in the block mask.
+    #[inline]
+    fn visit_mask(&mut self, bytes: &[u8], offset: usize, mut mask: u32) -> Option<(usize, usize)> {
+        while mask != 0 {
+            let index = offset + mask.trailing_zeros() as usize;
+            if let Some(found) = self.visit(index, unsafe { *bytes.get_unchecked(index) }) {
+                return Some(found);
+            }
+            mask &= mask - 1;
+        }
+        None
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::State;
+    use std::arch::x86_64::*;
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn scan_avx2(bytes: &[u8], start: usize) -> Option<(usize, usize)> {
+        let mut state = State::new(start);
+        let mut offset = start + 1;
+        let quote = _mm256_set1_epi8(b'"' as i8);
+        let backslash = _mm256_set1_epi8(b'\\' as i8);
+        let comma = _mm256_set1_epi8(b',' as i8);
+        // `[` and `]` only differ from `{` and `}` by the 0x20 bit
+        let case = _mm256_set1_epi8(0x20);
+        let open = _mm256_set1_epi8(b'{' as i8);
+        let close = _mm256_set1_epi8(b'}' as i8);
+        while offset + 32 <= bytes.len() {
+            let block = unsafe { _mm256_loadu_si256(bytes.as_ptr().add(offset) as *const __m256i) };
+            let folded = _mm256_or_si256(block, case);
+            let structural = _mm256_or_si256(
+                _mm256_or_si256(_mm256_cmpeq_epi8(block, quote), _mm256_cmpeq_epi8(block, backslash)),
+                _mm256_or_si256(
+                    _mm256_cmpeq_epi8(block, comma),
+                    _mm256_or_si256(_mm256_cmpeq_epi8(folded, open), _mm256_cmpeq_epi8(folded, close)),
+                ),
+            );
+            let mask = _mm256_movemask_epi8(structural) as u32;
+            if let Some(found) = state.visit_mask(bytes, offset, mask) {
+                return Some(found);
+            }
+            offset += 32;
+        }
+        state.visit_from(bytes, offset)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn scan_sse2(bytes: &[u8], start: usize) -> Option<(usize, usize)> {
+        let mut state = State::new(start);
+        let mut offset = start + 1;
+        let quote = _mm_set1_epi8(b'"' as i8);
+        let backslash = _mm_set1_epi8(b'\\' as i8);
+        let comma = _mm_set1_epi8(b',' as i8);
+        // `[` and `]` only differ from `{` and `}` by the 0x20 bit
+        let case = _mm_set1_epi8(0x20);
+        let open = _mm_set1_epi8(b'{' as i8);
+        let close = _mm_set1_epi8(b'}' as i8);
+        while offset + 16 <= bytes.len() {
+            let block = unsafe { _mm_loadu_si128(bytes.as_ptr().add(offset) as *const __m128i) };
+            let folded = _mm_or_si128(block, case);
+            let structural = _mm_or_si128(
+                _mm_or_si128(_mm_cmpeq_epi8(block, quote), _mm_cmpeq_epi8(block, backslash)),
+                _mm_or_si128(
+                    _mm_cmpeq_epi8(block, comma),
+                    _mm_or_si128(_mm_cmpeq_epi8(folded, open), _mm_cmpeq_epi8(folded, close)),
+                ),
+            );
+            let mask = _mm_movemask_epi8(structural) as u32;
+            if let Some(found) = state.visit_mask(bytes, offset, mask) {
+                return Some(found);
+            }
+            offset += 16;
+        }
+        state.visit_from(bytes, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Case = (&'static [u8], Option<(usize, usize)>);
+
+    const CASES: &[Case] = &[
+        (b"[]", Some((2, 0))),
+        (b"{}", Some((2, 0))),
+        (b"[1,2,3]", Some((7, 2))),
+        (br#"[["1.0","2.0"],["3.0","4.0"],["5.0","6.0"]],"a":1"#, Some((43, 2))),
+        (br#"{"a":[1,{"b":"}],,"}],"c":"\"]\\"}"#, Some((34, 1))),
+        (br#"[" \\\" ] ", {"x":[",,,"]}, "\\\\"]"#, Some((35, 2))),
+        (b"[1,2", None),
+        (br#"["]"#, None),
+    ];
+
+    fn check(scan: impl Fn(&[u8], usize) -> Option<(usize, usize)>) {
+        for (bytes, expected) in CASES {
+            assert_eq!(*expected, scan(bytes, 0), "{}", String::from_utf8_lossy(bytes));
+            // same input shifted across block boundaries
+            for padding in 1..40 {
+                let mut padded = vec![b' '; padding];
+                padded.extend_from_slice(bytes);
+                assert_eq!(*expected, scan(&padded, padding), "{}", String::from_utf8_lossy(bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn should_scan_with_scalar() {
+        check(scan_scalar);
+    }
+
+    #[test]
+    fn should_scan_with_detected_features() {
+        check(scan);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn should_scan_with_sse2() {
+        check(|bytes, start| unsafe { x86::scan_sse2(bytes, start) });
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn should_scan_with_avx2() {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            check(|bytes, start| unsafe { x86::scan_avx2(bytes, start) });
+        }
+    }
+
+    #[test]
+    fn should_scan_long_values() {
+        let mut bytes = b"[".to_vec();
+        for index in 0..500 {
+            if index > 0 {
+                bytes.push(b',');
+            }
+            bytes.extend_from_slice(format!(r#"["{index}.5","a\\\"[{{,"]"#).as_bytes());
+        }
+        bytes.extend_from_slice(b"],1");
+        let expected = Some((bytes.len() - 2, 499));
+        assert_eq!(expected, scan_scalar(&bytes, 0));
+        assert_eq!(expected, scan(&bytes, 0));
+    }
+}
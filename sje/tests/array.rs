@@ -0,0 +1,118 @@
+use sje::error::Error;
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Trade {
+    #[sje(rename = "id")]
+    id: u64,
+    #[sje(rename = "price")]
+    price: String,
+    #[sje(rename = "qty")]
+    quantity: String,
+    #[sje(rename = "isBuyerMaker")]
+    is_buyer_maker: bool,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(array)]
+pub struct Trades {
+    #[sje(decoder = true)]
+    trades: Vec<Trade>,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(tuple)]
+pub struct Kline {
+    open_time: u64,
+    open: String,
+    close: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(array)]
+pub struct Klines(#[sje(decoder = true)] Vec<Kline>);
+
+const TRADES: &[u8] = br#"[{"id":28457,"price":"4.00000100","qty":"12.00000000","isBuyerMaker":true},{"id":28458,"price":"4.00000200","qty":"1.00000000","isBuyerMaker":false}]"#;
+
+#[test]
+fn should_decode_array_of_objects() {
+    let trades = TradesDecoder::decode(TRADES).unwrap();
+    assert_eq!(2, trades.len());
+    assert!(!trades.is_empty());
+    assert_eq!(TRADES, trades.as_slice());
+
+    let mut iter = trades.iter();
+    assert_eq!(2, iter.len());
+
+    let trade = iter.next().unwrap();
+    assert_eq!(28457, trade.id());
+    assert_eq!("4.00000100", trade.price_as_str());
+    assert!(trade.is_buyer_maker());
+    assert_eq!(1, iter.len());
+
+    let trade = iter.next().unwrap();
+    assert_eq!(28458, trade.id());
+    assert_eq!("1.00000000", trade.quantity_as_str());
+    assert!(!trade.is_buyer_maker());
+
+    assert!(iter.next().is_none());
+
+    let trades: Trades = trades.into();
+    assert_eq!(2, trades.trades.len());
+    assert_eq!(28458, trades.trades[1].id);
+}
+
+#[test]
+fn should_decode_empty_array() {
+    let trades = TradesDecoder::decode(b"[]").unwrap();
+    assert_eq!(0, trades.len());
+    assert!(trades.is_empty());
+    assert!(trades.into_iter().next().is_none());
+}
+
+#[test]
+fn should_decode_array_of_tuples() {
+    let klines = KlinesDecoder::decode(
+        br#"[[1499040000000,"0.01634790","0.01577100"],[1499040060000,"0.01577100","0.01600000"]]"#,
+    )
+    .unwrap();
+    assert_eq!(2, klines.len());
+
+    let open_times = klines.iter().map(|kline| kline.open_time()).collect::<Vec<_>>();
+    assert_eq!(vec![1499040000000, 1499040060000], open_times);
+
+    let klines: Klines = klines.into();
+    assert_eq!(
+        Klines(vec![
+            Kline {
+                open_time: 1499040000000,
+                open: "0.01634790".to_string(),
+                close: "0.01577100".to_string(),
+            },
+            Kline {
+                open_time: 1499040060000,
+                open: "0.01577100".to_string(),
+                close: "0.01600000".to_string(),
+            },
+        ]),
+        klines
+    );
+}
+
+#[test]
+fn should_fail_to_decode_when_not_an_array() {
+    assert!(matches!(TradesDecoder::decode(br#"{"id":28457}"#), Err(Error::Other(_))));
+}
+
+#[test]
+fn should_encode_array() {
+    let trades: Trades = TradesDecoder::decode(TRADES).unwrap().into();
+    let mut bytes = Vec::new();
+    TradesEncoder::encode_to_vec(&trades, &mut bytes).unwrap();
+    assert_eq!(TRADES, bytes.as_slice());
+
+    bytes.clear();
+    KlinesEncoder::encode_to_vec(&Klines(vec![]), &mut bytes).unwrap();
+    assert_eq!(b"[]", bytes.as_slice());
+}
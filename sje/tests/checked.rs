@@ -0,0 +1,58 @@
+#![cfg(feature = "checked")]
+
+use sje::error::Error;
+use sje_derive::Decoder;
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+struct Trade {
+    #[sje(rename = "e", len = 5)]
+    event_type: String,
+    #[sje(rename = "E", len = 13)]
+    event_time: u64,
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "m")]
+    is_buyer_maker: bool,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+struct TradeStream {
+    #[sje(rename = "stream")]
+    stream: String,
+    #[sje(rename = "data", decoder = true)]
+    data: Trade,
+}
+
+const TRADE: &[u8] = br#"{"e":"trade","E":1705085312569,"s":"BTCUSDT","m":true}"#;
+
+#[test]
+fn should_decode_complete_message() {
+    let trade = TradeDecoder::decode(TRADE).unwrap();
+    assert_eq!("trade", trade.event_type());
+    assert_eq!(1705085312569, trade.event_time());
+    assert_eq!("BTCUSDT", trade.symbol());
+    assert!(trade.is_buyer_maker());
+}
+
+#[test]
+fn should_reject_every_truncated_message() {
+    for len in 0..TRADE.len() - 1 {
+        assert!(TradeDecoder::decode(&TRADE[..len]).is_err(), "decoded truncated message of length {len}");
+    }
+}
+
+#[test]
+fn should_reject_shorter_than_expected_values() {
+    assert!(matches!(TradeDecoder::decode(br#"{"e":"tr"}"#), Err(Error::MissingField("event_type"))));
+    assert!(matches!(TradeDecoder::decode(br#"{"e":"trade","E":1}"#), Err(Error::MissingField(_))));
+}
+
+#[test]
+fn should_reject_truncated_nested_message() {
+    let json = br#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1705085312569"#;
+    assert!(TradeStreamDecoder::decode(json).is_err());
+}
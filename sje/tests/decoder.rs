@@ -38,9 +38,37 @@ struct ListenKeyExpired {
     listen_key: String,
 }
 
+#[derive(Decoder, Debug)]
+#[sje(object, verify_keys)]
+#[allow(dead_code)]
+struct AggTrade {
+    #[sje(rename = "e", len = 8)]
+    event_type: String,
+    #[sje(rename = "E")]
+    event_time: u64,
+    #[sje(rename = "s")]
+    symbol: String,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object, fallback)]
+#[allow(dead_code)]
+struct BookTicker {
+    #[sje(rename = "u")]
+    update_id: u64,
+    #[sje(rename = "s", len = 7)]
+    symbol: String,
+    #[sje(rename = "b")]
+    bid_price: String,
+    #[sje(rename = "l")]
+    levels: Vec<(String, String)>,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{ListenKeyExpiredDecoder, Trade, TradeDecoder};
+    use crate::{AggTradeDecoder, BookTicker, BookTickerDecoder, ListenKeyExpiredDecoder, Trade, TradeDecoder};
+    use sje::error::Error;
+    use std::borrow::Cow;
     use std::str::from_utf8_unchecked;
 
     #[test]
@@ -64,6 +92,21 @@ mod tests {
         assert!(trade.is_buyer_maker);
     }
 
+    #[test]
+    fn should_decode_strings_with_escaped_quotes() {
+        let agg_trade = AggTradeDecoder::decode(br#"{"e":"aggTrade","E":1705085312569,"s":"BTC\"USDT\\"}"#).unwrap();
+        assert_eq!(1705085312569, agg_trade.event_time());
+        assert_eq!(r#"BTC\"USDT\\"#, agg_trade.symbol_as_str());
+        assert_eq!(r#"BTC"USDT\"#, agg_trade.symbol_unescaped().unwrap());
+        assert!(matches!(agg_trade.event_type_unescaped().unwrap(), Cow::Borrowed("aggTrade")));
+
+        let ticker: BookTicker = BookTickerDecoder::decode(br#"{"b":"\"25\"","s":"BNBUSDT","u":400900217,"l":[]}"#)
+            .unwrap()
+            .into();
+        assert_eq!(r#"\"25\""#, ticker.bid_price);
+        assert_eq!(400900217, ticker.update_id);
+    }
+
     #[test]
     fn should_decode_listen_key_expired() {
         let listen_key_expired = ListenKeyExpiredDecoder::decode(br#"{"e": "listenKeyExpired","E": "1743606297156","listenKey": "FdffIUjdfd343DtLMw2tKS87iL2HpYRniDWpkoxWCb4fwP2yzJXalBlBNnz471cE"}"#).unwrap();
@@ -71,4 +114,58 @@ mod tests {
         assert_eq!(1743606297156, listen_key_expired.event_time());
         assert_eq!("FdffIUjdfd343DtLMw2tKS87iL2HpYRniDWpkoxWCb4fwP2yzJXalBlBNnz471cE", listen_key_expired.listen_key());
     }
+
+    #[test]
+    fn should_decode_with_verified_keys() {
+        let agg_trade = AggTradeDecoder::decode(br#"{"e":"aggTrade","E":1705085312569,"s":"BTCUSDT"}"#).unwrap();
+        assert_eq!("aggTrade", agg_trade.event_type());
+        assert_eq!(1705085312569, agg_trade.event_time());
+        assert_eq!("BTCUSDT", agg_trade.symbol());
+    }
+
+    #[test]
+    fn should_reject_unexpected_key() {
+        let err = AggTradeDecoder::decode(br#"{"e":"aggTrade","s":"BTCUSDT","E":1705085312569}"#).unwrap_err();
+        match err {
+            Error::UnexpectedKey { expected, found } => {
+                assert_eq!("E", expected);
+                assert_eq!("s", found);
+            }
+            _ => panic!("unexpected error {err}"),
+        }
+    }
+
+    #[test]
+    fn should_decode_in_order_with_fallback_enabled() {
+        let ticker =
+            BookTickerDecoder::decode(br#"{"u":400900217,"s":"BNBUSDT","b":"25.35190000","l":[["1.0","2.0"]]}"#)
+                .unwrap();
+        assert_eq!(400900217, ticker.update_id());
+        assert_eq!("BNBUSDT", ticker.symbol());
+        assert_eq!("25.35190000", ticker.bid_price());
+        assert_eq!(1, ticker.levels_count());
+    }
+
+    #[test]
+    fn should_fall_back_to_key_lookup() {
+        let json =
+            br#"{"s":"BNBUSDT", "x":{"u":1}, "l":[["1.0","2.0"],["3.0","4.0"]], "b":"25.35190000", "u":400900217}"#;
+        let ticker: BookTicker = BookTickerDecoder::decode(json).unwrap().into();
+        assert_eq!(400900217, ticker.update_id);
+        assert_eq!("BNBUSDT", ticker.symbol);
+        assert_eq!("25.35190000", ticker.bid_price);
+        assert_eq!(
+            vec![
+                ("1.0".to_string(), "2.0".to_string()),
+                ("3.0".to_string(), "4.0".to_string())
+            ],
+            ticker.levels
+        );
+    }
+
+    #[test]
+    fn should_report_missing_field_after_fallback() {
+        let err = BookTickerDecoder::decode(br#"{"s":"BNBUSDT","u":400900217,"l":[]}"#).unwrap_err();
+        assert!(matches!(err, Error::MissingField("bid_price")));
+    }
 }
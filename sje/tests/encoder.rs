@@ -0,0 +1,135 @@
+use sje_derive::{Decoder, Encoder};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Price(f64);
+
+impl FromStr for Price {
+    type Err = <f64 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Display for Price {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct NewOrder {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "p", ty = "string")]
+    price: Price,
+    #[sje(rename = "q")]
+    quantity: u64,
+    #[sje(rename = "r")]
+    reduce_only: bool,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Position {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct PositionUpdate {
+    #[sje(rename = "t")]
+    timestamp: u64,
+    #[sje(rename = "u", decoder = true)]
+    updates: Vec<Position>,
+    #[sje(rename = "b")]
+    bids: Vec<(Price, Price)>,
+}
+
+#[test]
+fn should_encode_into_slice() {
+    let order = NewOrder {
+        symbol: "BTCUSDT".to_string(),
+        price: Price(43520.5),
+        quantity: 3,
+        reduce_only: true,
+    };
+
+    let mut bytes = [0u8; 128];
+    let len = NewOrderEncoder::encode(&order, &mut bytes).unwrap();
+    assert_eq!(br#"{"s":"BTCUSDT","p":"43520.5","q":3,"r":true}"#, &bytes[..len]);
+
+    let decoded: NewOrder = NewOrderDecoder::decode(&bytes[..len]).unwrap().into();
+    assert_eq!(order, decoded);
+}
+
+#[test]
+fn should_encode_into_vec() {
+    let order = NewOrder {
+        symbol: "ETH\"USDT".to_string(),
+        price: Price(1.25),
+        quantity: 10,
+        reduce_only: false,
+    };
+
+    let mut bytes = b"prefix".to_vec();
+    let len = NewOrderEncoder::encode_to_vec(&order, &mut bytes).unwrap();
+    assert_eq!(br#"{"s":"ETH\"USDT","p":"1.25","q":10,"r":false}"#, &bytes[6..]);
+    assert_eq!(bytes.len() - 6, len);
+}
+
+#[test]
+fn should_fail_when_slice_is_too_small() {
+    let order = NewOrder {
+        symbol: "BTCUSDT".to_string(),
+        price: Price(43520.5),
+        quantity: 3,
+        reduce_only: true,
+    };
+
+    let mut bytes = [0u8; 16];
+    assert!(matches!(NewOrderEncoder::encode(&order, &mut bytes), Err(sje::error::Error::BufferOverflow(_))));
+}
+
+#[test]
+fn should_encode_arrays() {
+    let update = PositionUpdate {
+        timestamp: 1746699621,
+        updates: vec![
+            Position {
+                symbol: "btcusdt".to_string(),
+                amount: 100,
+            },
+            Position {
+                symbol: "ethusdt".to_string(),
+                amount: 200,
+            },
+        ],
+        bids: vec![(Price(2.6461), Price(6404.9))],
+    };
+
+    let mut bytes = Vec::new();
+    PositionUpdateEncoder::encode_to_vec(&update, &mut bytes).unwrap();
+    assert_eq!(
+        br#"{"t":1746699621,"u":[{"s":"btcusdt","a":100},{"s":"ethusdt","a":200}],"b":[["2.6461","6404.9"]]}"#,
+        bytes.as_slice()
+    );
+
+    let decoded: PositionUpdate = PositionUpdateDecoder::decode(&bytes).unwrap().into();
+    assert_eq!(update, decoded);
+
+    let update = PositionUpdate {
+        timestamp: 1,
+        updates: vec![],
+        bids: vec![],
+    };
+    bytes.clear();
+    PositionUpdateEncoder::encode_to_vec(&update, &mut bytes).unwrap();
+    assert_eq!(br#"{"t":1,"u":[],"b":[]}"#, bytes.as_slice());
+}
@@ -0,0 +1,60 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+use std::str::FromStr;
+
+#[derive(Decoder, Debug, Copy, Clone, PartialEq)]
+pub enum Side {
+    #[sje(rename = "BUY", alias = "B", alias = "buy")]
+    Buy,
+    #[sje(rename = "SELL", alias = "S")]
+    Sell,
+}
+
+#[derive(Decoder, Debug, Copy, Clone, PartialEq)]
+pub enum OrderStatus {
+    New,
+    Filled,
+    #[sje(other)]
+    Unknown,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Order {
+    #[sje(rename = "S", ty = "string")]
+    side: Side,
+    #[sje(rename = "X", ty = "string")]
+    status: OrderStatus,
+}
+
+#[test]
+fn should_parse_renamed_variants() {
+    assert_eq!(Side::Buy, Side::try_from(b"BUY".as_slice()).unwrap());
+    assert_eq!(Side::Buy, Side::try_from(b"B".as_slice()).unwrap());
+    assert_eq!(Side::Buy, Side::from_str("buy").unwrap());
+    assert_eq!(Side::Sell, Side::from_str("SELL").unwrap());
+    assert_eq!(Side::Sell, Side::from_str("S").unwrap());
+}
+
+#[test]
+fn should_fail_on_unrecognised_variant() {
+    assert!(matches!(Side::from_str("Buy"), Err(Error::UnknownVariant(name)) if name == "Buy"));
+    assert!(matches!(Side::try_from([0xff].as_slice()), Err(Error::UnknownVariant(_))));
+}
+
+#[test]
+fn should_fall_back_to_other_variant() {
+    assert_eq!(OrderStatus::New, OrderStatus::from_str("New").unwrap());
+    assert_eq!(OrderStatus::Filled, OrderStatus::from_str("Filled").unwrap());
+    assert_eq!(OrderStatus::Unknown, OrderStatus::from_str("EXPIRED").unwrap());
+}
+
+#[test]
+fn should_decode_enum_fields() {
+    let order = OrderDecoder::decode(br#"{"S":"SELL","X":"Filled"}"#).unwrap();
+    assert_eq!(Side::Sell, order.side());
+    assert_eq!(OrderStatus::Filled, order.status());
+
+    let order = OrderDecoder::decode(br#"{"S":"HOLD","X":"Filled"}"#).unwrap();
+    assert!(matches!(order.side_as_lazy_field().get(), Err(Error::Parse(value)) if value == "HOLD"));
+}
@@ -1,4 +1,5 @@
-use sje_derive::Decoder;
+use sje_derive::{Decoder, Encoder};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -37,6 +38,38 @@ pub struct L2Update {
     asks: Vec<(Price, Quantity)>,
 }
 
+impl Display for Price {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Book {
+    #[sje(rename = "b", ty = "number")]
+    buy_levels: Vec<(Price, Quantity)>,
+    #[sje(rename = "a", ty = "string, number, number")]
+    sell_levels: Vec<(Price, Quantity, u32)>,
+    #[sje(rename = "t")]
+    trades: Vec<(u64, f64, bool)>,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Depth {
+    #[sje(rename = "b")]
+    top_levels: Vec<(Price, Quantity)>,
+    #[sje(rename = "a", remainder)]
+    raw_levels: Vec<(Price, Quantity, String)>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,4 +147,52 @@ mod tests {
         assert_eq!(Some((Price(2.6468), Quantity(22540.8))), asks.next());
         assert_eq!(None, asks.next());
     }
+
+    #[test]
+    fn should_decode_tuple_elements_by_type() {
+        const BOOK: &[u8] = br#"{"b":[[95732.6,2.073]],"a":[["95733.1",0.5,3]],"t":[[1,0.25,true],[2,1.5,false]]}"#;
+        let book = BookDecoder::decode(BOOK).unwrap();
+
+        let mut bids = book.buy_levels().into_iter();
+        assert_eq!(Some((Price(95732.6), Quantity(2.073))), bids.next());
+        assert_eq!(None, bids.next());
+
+        let mut asks = book.sell_levels().into_iter();
+        assert_eq!(Some((Price(95733.1), Quantity(0.5), 3)), asks.next());
+        assert_eq!(None, asks.next());
+
+        let trades: Vec<(u64, f64, bool)> = book.trades().into();
+        assert_eq!(vec![(1, 0.25, true), (2, 1.5, false)], trades);
+
+        let book: Book = BookDecoder::decode(BOOK).unwrap().into();
+        let mut bytes = Vec::new();
+        BookEncoder::encode_to_vec(&book, &mut bytes).unwrap();
+        assert_eq!(BOOK, bytes.as_slice());
+    }
+
+    #[test]
+    fn should_ignore_extra_tuple_elements() {
+        const DEPTH: &[u8] =
+            br#"{"b":[["1.0","2.0","0"],["3.0","4.0",[]]],"a":[["5.0","6.0","0",[1,2]],["7.0","8.0"]]}"#;
+        let depth = DepthDecoder::decode(DEPTH).unwrap();
+
+        let levels: Vec<(Price, Quantity)> = depth.top_levels().into();
+        assert_eq!(vec![(Price(1.0), Quantity(2.0)), (Price(3.0), Quantity(4.0))], levels);
+
+        let mut levels = depth.raw_levels().into_iter();
+        assert_eq!(Some((Price(5.0), Quantity(6.0), r#""0",[1,2]"#.to_string())), levels.next());
+        assert_eq!(Some((Price(7.0), Quantity(8.0), String::new())), levels.next());
+        assert_eq!(None, levels.next());
+
+        let depth = Depth {
+            top_levels: vec![],
+            raw_levels: vec![
+                (Price(5.0), Quantity(6.0), r#""0""#.to_string()),
+                (Price(7.0), Quantity(8.0), String::new()),
+            ],
+        };
+        let mut bytes = Vec::new();
+        DepthEncoder::encode_to_vec(&depth, &mut bytes).unwrap();
+        assert_eq!(br#"{"b":[],"a":[["5","6","0"],["7","8"]]}"#, bytes.as_slice());
+    }
 }
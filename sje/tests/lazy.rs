@@ -0,0 +1,110 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+pub struct Position {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object, lazy)]
+#[allow(dead_code)]
+pub struct Trade {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "E", skip)]
+    event_time: u64,
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "p")]
+    price: String,
+    #[sje(rename = "c")]
+    client_order_id: Option<String>,
+    #[sje(rename = "P", decoder = true)]
+    position: Position,
+    #[sje(rename = "b")]
+    bids: Vec<(String, String)>,
+    #[sje(rename = "m")]
+    is_buyer_maker: bool,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(tuple, lazy)]
+#[allow(dead_code)]
+pub struct Level {
+    price: String,
+    quantity: String,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object, lazy)]
+#[allow(dead_code)]
+pub struct Amount {
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+const TRADE: &[u8] = br#"{"e":"trade","E":1705085312569,"s":"BTC\"USDT","p":"43520.00","P":{"s":"btcusdt","a":100},"b":[["1.0","2.0"],["3.0","4.0"]],"m":true}"#;
+
+#[test]
+fn should_locate_fields_on_first_access() {
+    let trade = TradeLazyDecoder::wrap(TRADE);
+    assert!(trade.is_buyer_maker().unwrap());
+    assert_eq!("43520.00", trade.price_as_str().unwrap());
+    assert_eq!(r#"BTC\"USDT"#, trade.symbol_as_str().unwrap());
+    assert_eq!(r#"BTC"USDT"#, trade.symbol_unescaped().unwrap());
+    assert_eq!("trade", trade.event_type().unwrap());
+    assert_eq!(None, trade.client_order_id().unwrap());
+
+    let position = trade.position().unwrap();
+    assert_eq!("btcusdt", position.symbol_as_str());
+    assert_eq!(100, position.amount());
+
+    assert_eq!(2, trade.bids_count().unwrap());
+    let bids: Vec<(String, String)> = trade.bids().unwrap().into();
+    assert_eq!(
+        vec![
+            ("1.0".to_string(), "2.0".to_string()),
+            ("3.0".to_string(), "4.0".to_string())
+        ],
+        bids
+    );
+}
+
+#[test]
+fn should_scan_only_up_to_the_requested_field() {
+    // the tail is malformed but never reached
+    let trade = TradeLazyDecoder::wrap(br#"{"e":"trade","E":1705085312569,"s":"BTCUSDT","p":"#);
+    assert_eq!("BTCUSDT", trade.symbol_as_str().unwrap());
+    assert_eq!("trade", trade.event_type_as_str().unwrap());
+}
+
+#[test]
+fn should_decode_optional_field() {
+    let trade = TradeLazyDecoder::wrap(
+        br#"{"e":"trade","E":1,"s":"BTCUSDT","p":"1.5","c":"abc","P":{"s":"btcusdt","a":1},"b":[],"m":false}"#,
+    );
+    assert_eq!(Some("abc".to_string()), trade.client_order_id().unwrap());
+    assert_eq!(Some("abc"), trade.client_order_id_as_str().unwrap());
+    assert_eq!(0, trade.bids_count().unwrap());
+    assert!(!trade.is_buyer_maker().unwrap());
+}
+
+#[test]
+fn should_decode_tuple_elements() {
+    let level = LevelLazyDecoder::wrap(br#"["46215.01","3.48"]"#);
+    assert_eq!("3.48", level.quantity().unwrap());
+    assert_eq!("46215.01", level.price().unwrap());
+}
+
+#[test]
+fn should_fail_when_value_cannot_be_parsed() {
+    let amount = AmountLazyDecoder::wrap(br#"{"a":-1}"#);
+    assert_eq!("-1", amount.amount_as_str().unwrap());
+    assert!(matches!(amount.amount(), Err(Error::Parse(_))));
+}
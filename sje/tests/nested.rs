@@ -0,0 +1,83 @@
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Trade {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "p")]
+    price: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct TradeStream {
+    #[sje(rename = "stream")]
+    stream: String,
+    #[sje(rename = "data", decoder = true)]
+    data: Trade,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object, fallback)]
+pub struct ExecutionReport {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "o", decoder = true)]
+    order: Trade,
+    #[sje(rename = "T")]
+    transaction_time: u64,
+}
+
+#[test]
+fn should_decode_nested_object() {
+    let json = br#"{"stream":"btcusdt@trade","data":{"e":"trade","s":"BTCUSDT","p":"43520.00000000"}}"#;
+    let stream = TradeStreamDecoder::decode(json).unwrap();
+    assert_eq!("btcusdt@trade", stream.stream());
+    assert_eq!(r#"{"e":"trade","s":"BTCUSDT","p":"43520.00000000"}"#, stream.data_as_str());
+
+    let trade = stream.data();
+    assert_eq!("trade", trade.event_type());
+    assert_eq!("BTCUSDT", trade.symbol_as_str());
+    assert_eq!("43520.00000000", trade.price());
+}
+
+#[test]
+fn should_convert_nested_object_to_owned() {
+    let json = br#"{"stream":"btcusdt@trade","data":{"e":"trade","s":"BTCUSDT","p":"43520.00000000"}}"#;
+    let stream: TradeStream = TradeStreamDecoder::decode(json).unwrap().into();
+    assert_eq!(
+        TradeStream {
+            stream: "btcusdt@trade".to_string(),
+            data: Trade {
+                event_type: "trade".to_string(),
+                symbol: "BTCUSDT".to_string(),
+                price: "43520.00000000".to_string(),
+            },
+        },
+        stream
+    );
+
+    let mut bytes = Vec::new();
+    TradeStreamEncoder::encode_to_vec(&stream, &mut bytes).unwrap();
+    assert_eq!(json.as_slice(), bytes.as_slice());
+}
+
+#[test]
+fn should_decode_nested_object_with_fallback() {
+    let json = br#"{"e":"executionReport","T":1,"o":{"e":"trade","s":"BTCUSDT","p":"1.0"}}"#;
+    let report = ExecutionReportDecoder::decode(json).unwrap();
+    assert_eq!(1, report.transaction_time());
+    assert_eq!("BTCUSDT", report.order().symbol());
+}
+
+#[test]
+fn should_decode_nested_object_with_brackets_in_strings() {
+    let json = br#"{"stream":"btcusdt@trade","data":{"e":"trade","s":"x}y","p":"{\"}"}}"#;
+    let stream = TradeStreamDecoder::decode(json).unwrap();
+    assert_eq!(r#"{"e":"trade","s":"x}y","p":"{\"}"}"#, stream.data_as_str());
+    assert_eq!("x}y", stream.data().symbol_as_str());
+    assert_eq!(r#"{\"}"#, stream.data().price_as_str());
+}
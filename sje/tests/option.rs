@@ -0,0 +1,163 @@
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct DepthUpdate {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "E")]
+    event_time: u64,
+    #[sje(rename = "pu")]
+    previous_update_id: Option<u64>,
+    #[sje(rename = "u")]
+    update_id: u64,
+    #[sje(rename = "M")]
+    is_best_match: Option<bool>,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object, fallback)]
+pub struct Order {
+    #[sje(rename = "c")]
+    client_order_id: Option<String>,
+    #[sje(rename = "i")]
+    order_id: u64,
+}
+
+#[test]
+fn should_decode_present_optional_fields() {
+    let update =
+        DepthUpdateDecoder::decode(br#"{"e":"depthUpdate","E":1739836781765,"pu":100,"u":101,"M":true}"#).unwrap();
+    assert_eq!("depthUpdate", update.event_type());
+    assert_eq!(Some(100), update.previous_update_id());
+    assert_eq!(Some("100"), update.previous_update_id_as_str());
+    assert_eq!(Some(b"100".as_slice()), update.previous_update_id_as_slice());
+    assert_eq!(&100, update.previous_update_id_as_lazy_field().unwrap().get_ref().unwrap());
+    assert_eq!(101, update.update_id());
+    assert_eq!(Some(true), update.is_best_match());
+}
+
+#[test]
+fn should_decode_absent_optional_fields() {
+    let update = DepthUpdateDecoder::decode(br#"{"e":"depthUpdate","E":1739836781765,"u":101}"#).unwrap();
+    assert_eq!(None, update.previous_update_id());
+    assert_eq!(None, update.previous_update_id_as_str());
+    assert!(update.previous_update_id_as_lazy_field().is_none());
+    assert_eq!(101, update.update_id());
+    assert_eq!(None, update.is_best_match());
+
+    let update: DepthUpdate = update.into();
+    assert_eq!(
+        DepthUpdate {
+            event_type: "depthUpdate".to_string(),
+            event_time: 1739836781765,
+            previous_update_id: None,
+            update_id: 101,
+            is_best_match: None,
+        },
+        update
+    );
+}
+
+#[test]
+fn should_decode_optional_fields_with_fallback() {
+    let order = OrderDecoder::decode(br#"{"c":"abc","i":1}"#).unwrap();
+    assert_eq!(Some("abc".to_string()), order.client_order_id());
+    assert_eq!(1, order.order_id());
+
+    let order = OrderDecoder::decode(br#"{"i":1}"#).unwrap();
+    assert_eq!(None, order.client_order_id());
+    assert_eq!(1, order.order_id());
+
+    let order = OrderDecoder::decode(br#"{"x":0,"i":1,"c":"abc"}"#).unwrap();
+    assert_eq!(Some("abc".to_string()), order.client_order_id());
+    assert_eq!(1, order.order_id());
+
+    let order = OrderDecoder::decode(br#"{"i":1,"c":"abc"}"#).unwrap();
+    assert_eq!(Some("abc".to_string()), order.client_order_id());
+    assert_eq!(1, order.order_id());
+}
+
+#[test]
+fn should_omit_absent_optional_fields_when_encoding() {
+    let update = DepthUpdate {
+        event_type: "depthUpdate".to_string(),
+        event_time: 1739836781765,
+        previous_update_id: None,
+        update_id: 101,
+        is_best_match: Some(false),
+    };
+    let mut bytes = Vec::new();
+    DepthUpdateEncoder::encode_to_vec(&update, &mut bytes).unwrap();
+    assert_eq!(br#"{"e":"depthUpdate","E":1739836781765,"u":101,"M":false}"#, bytes.as_slice());
+
+    let order = Order {
+        client_order_id: None,
+        order_id: 1,
+    };
+    bytes.clear();
+    OrderEncoder::encode_to_vec(&order, &mut bytes).unwrap();
+    assert_eq!(br#"{"i":1}"#, bytes.as_slice());
+
+    let order = Order {
+        client_order_id: Some("abc".to_string()),
+        order_id: 1,
+    };
+    bytes.clear();
+    OrderEncoder::encode_to_vec(&order, &mut bytes).unwrap();
+    assert_eq!(br#"{"c":"abc","i":1}"#, bytes.as_slice());
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Execution {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "p", nullable)]
+    stop_price: Option<String>,
+    #[sje(rename = "q")]
+    quantity: Option<u64>,
+    #[sje(rename = "m")]
+    is_maker: bool,
+}
+
+#[test]
+fn should_decode_null_values() {
+    let execution = ExecutionDecoder::decode(br#"{"s":"BTCUSDT","p":null,"q":null,"m":true}"#).unwrap();
+    assert_eq!("BTCUSDT", execution.symbol());
+    assert_eq!(None, execution.stop_price());
+    assert_eq!(None, execution.stop_price_unescaped().unwrap());
+    assert_eq!(None, execution.quantity());
+    assert!(execution.is_maker());
+
+    let execution = ExecutionDecoder::decode(br#"{"s":"BTCUSDT","p":"1.5","q":7,"m":false}"#).unwrap();
+    assert_eq!(Some("1.5".to_string()), execution.stop_price());
+    assert_eq!(Some("1.5"), execution.stop_price_unescaped().unwrap().as_deref());
+    assert_eq!(Some(7), execution.quantity());
+    assert!(!execution.is_maker());
+
+    let execution = ExecutionDecoder::decode(br#"{"s":"BTCUSDT","p":null,"m":false}"#).unwrap();
+    assert_eq!(None, execution.stop_price());
+    assert_eq!(None, execution.quantity());
+    assert!(!execution.is_maker());
+}
+
+#[test]
+fn should_decode_null_values_with_fallback() {
+    let order = OrderDecoder::decode(br#"{"i":1,"c":null}"#).unwrap();
+    assert_eq!(None, order.client_order_id());
+    assert_eq!(1, order.order_id());
+}
+
+#[test]
+fn should_encode_null_values() {
+    let execution = Execution {
+        symbol: "BTCUSDT".to_string(),
+        stop_price: None,
+        quantity: None,
+        is_maker: true,
+    };
+    let mut bytes = Vec::new();
+    ExecutionEncoder::encode_to_vec(&execution, &mut bytes).unwrap();
+    assert_eq!(br#"{"s":"BTCUSDT","p":null,"m":true}"#, bytes.as_slice());
+}
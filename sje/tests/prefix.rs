@@ -0,0 +1,73 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+pub struct DepthUpdate {
+    #[sje(rename = "e", until)]
+    event_type: String,
+    #[sje(rename = "E", skip)]
+    event_time: u64,
+    #[sje(rename = "s", until)]
+    symbol: String,
+    #[sje(rename = "U")]
+    first_update_id: u64,
+    #[sje(rename = "b", until)]
+    bids: Vec<(String, String)>,
+    #[sje(rename = "a")]
+    asks: Vec<(String, String)>,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object, fallback)]
+#[allow(dead_code)]
+pub struct Order {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "i", until)]
+    order_id: u64,
+    #[sje(rename = "p")]
+    price: String,
+}
+
+const DEPTH_UPDATE: &[u8] = br#"{"e":"depthUpdate","E":1704907109810,"s":"BTCUSDT","U":41933235159,"b":[["46215.01","3.48"],["46214.99","0.0"]],"a":[["46215.02","5.2"]]}"#;
+
+#[test]
+fn should_decode_leading_fields_only() {
+    let update = DepthUpdateDecoder::decode_until_symbol(DEPTH_UPDATE).unwrap();
+    assert_eq!("depthUpdate", update.event_type());
+    assert_eq!("BTCUSDT", update.symbol_as_str());
+
+    let update = DepthUpdateDecoder::decode_until_event_type(DEPTH_UPDATE).unwrap();
+    assert_eq!("depthUpdate", update.event_type_as_str());
+
+    let update = DepthUpdateDecoder::decode_until_bids(DEPTH_UPDATE).unwrap();
+    assert_eq!(41933235159, update.first_update_id());
+    assert_eq!(2, update.bids_count());
+    let bids: Vec<(String, String)> = update.bids().into();
+    assert_eq!(("46214.99".to_string(), "0.0".to_string()), bids[1]);
+}
+
+#[test]
+fn should_not_scan_past_the_field() {
+    // rest of the message is truncated and would fail the full decode
+    let json = br#"{"e":"depthUpdate","E":1704907109810,"s":"BTCUSDT","U":4193"#;
+    assert!(DepthUpdateDecoder::decode(json).is_err());
+    let update = DepthUpdateUntilSymbolDecoder::decode(json).unwrap();
+    assert_eq!("BTCUSDT", update.symbol());
+}
+
+#[test]
+fn should_decode_leading_fields_with_fallback() {
+    let order = OrderDecoder::decode_until_order_id(br#"{"s":"BTCUSDT","i":7,"p":"1.0"}"#).unwrap();
+    assert_eq!("BTCUSDT", order.symbol());
+    assert_eq!(7, order.order_id());
+
+    let order = OrderDecoder::decode_until_order_id(br#"{"p":"1.0","i":7,"s":"BTCUSDT"}"#).unwrap();
+    assert_eq!("BTCUSDT", order.symbol());
+    assert_eq!(7, order.order_id());
+
+    let err = OrderDecoder::decode_until_order_id(br#"{"p":"1.0","s":"BTCUSDT"}"#).unwrap_err();
+    assert!(matches!(err, Error::MissingField("order_id")));
+}
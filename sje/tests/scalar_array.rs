@@ -0,0 +1,142 @@
+use sje_derive::{Decoder, Encoder};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Price(f64);
+
+impl FromStr for Price {
+    type Err = <f64 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Display for Price {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Snapshot {
+    #[sje(rename = "i")]
+    ids: Vec<u64>,
+    #[sje(rename = "s")]
+    symbols: Vec<String>,
+    #[sje(rename = "f")]
+    flags: Vec<bool>,
+    #[sje(rename = "p", ty = "string")]
+    prices: Vec<Price>,
+    #[sje(rename = "l", ty = "number")]
+    limits: Vec<Price>,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(array)]
+pub struct OrderIds(Vec<u64>);
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Quote {
+    #[sje(rename = "p")]
+    quoted_prices: Vec<Price>,
+    #[sje(rename = "l", ty = "object")]
+    raw_levels: Vec<RawLevel>,
+}
+
+/// Element parsed from the raw object text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawLevel(String);
+
+impl FromStr for RawLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.to_string()))
+    }
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object, whitespace = "tolerant")]
+pub struct Pretty {
+    #[sje(rename = "i")]
+    values: Vec<i64>,
+    #[sje(rename = "f")]
+    states: Vec<bool>,
+}
+
+const SNAPSHOT: &[u8] =
+    br#"{"i":[1,22,333],"s":["BTCUSDT","ETHUSDT"],"f":[true,false],"p":["1.5","2.25"],"l":[10.5,0.1]}"#;
+
+#[test]
+fn should_iterate_over_scalar_elements() {
+    let snapshot = SnapshotDecoder::decode(SNAPSHOT).unwrap();
+
+    let mut ids = snapshot.ids().into_iter();
+    assert_eq!(3, ids.len());
+    assert_eq!(Some(1), ids.next());
+    assert_eq!(Some(22), ids.next());
+    assert_eq!(Some(333), ids.next());
+    assert_eq!(None, ids.next());
+
+    let symbols: Vec<String> = snapshot.symbols().into();
+    assert_eq!(vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()], symbols);
+
+    let flags: Vec<bool> = snapshot.flags().into();
+    assert_eq!(vec![true, false], flags);
+
+    let prices: Vec<Price> = snapshot.prices().into();
+    assert_eq!(vec![Price(1.5), Price(2.25)], prices);
+
+    let limits: Vec<Price> = snapshot.limits().into();
+    assert_eq!(vec![Price(10.5), Price(0.1)], limits);
+}
+
+#[test]
+fn should_round_trip_scalar_arrays() {
+    let snapshot: Snapshot = SnapshotDecoder::decode(SNAPSHOT).unwrap().into();
+    let mut bytes = Vec::new();
+    SnapshotEncoder::encode_to_vec(&snapshot, &mut bytes).unwrap();
+    assert_eq!(SNAPSHOT, bytes.as_slice());
+}
+
+#[test]
+fn should_decode_top_level_scalar_array() {
+    let ids = OrderIdsDecoder::decode(b"[5,6]").unwrap();
+    assert_eq!(vec![5, 6], ids.iter().collect::<Vec<_>>());
+    assert_eq!(OrderIds(vec![5, 6]), ids.into());
+
+    let ids = OrderIdsDecoder::decode(b"[]").unwrap();
+    assert_eq!(None, ids.iter().next());
+}
+
+#[test]
+fn should_decode_scalar_arrays_with_whitespace() {
+    let pretty = PrettyDecoder::decode(b"{ \"i\" : [ 1 , -2 ,3 ], \"f\": [ true , false ] }").unwrap();
+    assert_eq!(vec![1, -2, 3], pretty.values().into_iter().collect::<Vec<_>>());
+    assert_eq!(vec![true, false], pretty.states().into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn should_read_user_type_elements_as_strings_by_default() {
+    let quote = QuoteDecoder::decode(br#"{"p":["1.0","2.0"],"l":[]}"#).unwrap();
+    assert_eq!(2, quote.quoted_prices().into_iter().len());
+    let prices: Vec<Price> = quote.quoted_prices().into();
+    assert_eq!(vec![Price(1.0), Price(2.0)], prices);
+}
+
+#[test]
+fn should_read_object_elements_with_override() {
+    let quote = QuoteDecoder::decode(br#"{"p":[],"l":[{"p":"1.0"},{"p":"2.0","q":{}}]}"#).unwrap();
+    let levels: Vec<RawLevel> = quote.raw_levels().into();
+    assert_eq!(
+        vec![
+            RawLevel(r#"{"p":"1.0"}"#.to_string()),
+            RawLevel(r#"{"p":"2.0","q":{}}"#.to_string())
+        ],
+        levels
+    );
+}
@@ -0,0 +1,99 @@
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Trade {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "b", skip)]
+    buyer_order_id: u64,
+    #[sje(rename = "a", skip)]
+    seller_order_id: (),
+    #[sje(rename = "p")]
+    price: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object, verify_keys, whitespace = "tolerant")]
+pub struct Depth {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "b", skip)]
+    bids: Vec<(String, String)>,
+    #[sje(rename = "u")]
+    update_id: u64,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(tuple)]
+pub struct Kline {
+    open_time: u64,
+    #[sje(skip)]
+    open: String,
+    close: String,
+}
+
+#[test]
+fn should_skip_values() {
+    let json = br#"{"e":"trade","s":"BTCUSDT","b":24269765071,"a":{"x":[1,"}"]},"p":"43520.00000000"}"#;
+    let trade = TradeDecoder::decode(json).unwrap();
+    assert_eq!("trade", trade.event_type());
+    assert_eq!("BTCUSDT", trade.symbol());
+    assert_eq!("43520.00000000", trade.price());
+
+    let trade: Trade = trade.into();
+    assert_eq!(
+        Trade {
+            event_type: "trade".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            buyer_order_id: 0,
+            seller_order_id: (),
+            price: "43520.00000000".to_string(),
+        },
+        trade
+    );
+}
+
+#[test]
+fn should_skip_values_with_whitespace() {
+    let json = br#"{ "s": "BNBUSDT", "b": [ ["1.0", "2.0"] ], "u": 7 }"#;
+    let depth = DepthDecoder::decode(json).unwrap();
+    assert_eq!("BNBUSDT", depth.symbol());
+    assert_eq!(7, depth.update_id());
+
+    let json = br#"{ "s": "BNBUSDT", "a": [], "u": 7 }"#;
+    assert!(DepthDecoder::decode(json).is_err());
+}
+
+#[test]
+fn should_skip_tuple_elements() {
+    let kline = KlineDecoder::decode(br#"[1499040000000,"0.01634790","0.01577100"]"#).unwrap();
+    assert_eq!(1499040000000, kline.open_time());
+    assert_eq!("0.01577100", kline.close());
+}
+
+#[test]
+fn should_not_encode_skipped_values() {
+    let trade = Trade {
+        event_type: "trade".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        buyer_order_id: 1,
+        seller_order_id: (),
+        price: "1.0".to_string(),
+    };
+    let mut bytes = Vec::new();
+    TradeEncoder::encode_to_vec(&trade, &mut bytes).unwrap();
+    assert_eq!(br#"{"e":"trade","s":"BTCUSDT","p":"1.0"}"#, bytes.as_slice());
+
+    let kline = Kline {
+        open_time: 1,
+        open: "2".to_string(),
+        close: "3".to_string(),
+    };
+    bytes.clear();
+    KlineEncoder::encode_to_vec(&kline, &mut bytes).unwrap();
+    assert_eq!(br#"[1,null,"3"]"#, bytes.as_slice());
+    assert_eq!("3", KlineDecoder::decode(&bytes).unwrap().close());
+}
@@ -0,0 +1,115 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Position {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct Account {
+    #[sje(rename = "E", also_as = "f64")]
+    event_time: u64,
+    #[sje(rename = "l")]
+    limit: Option<u64>,
+    #[sje(rename = "m", decoder = true)]
+    main: Position,
+    #[sje(rename = "p", decoder = true)]
+    positions: Vec<Position>,
+    #[sje(rename = "i")]
+    ids: Vec<u64>,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(array)]
+pub struct Portfolio(#[sje(decoder = true)] Vec<Position>);
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(union, tag = "e")]
+pub enum Event {
+    #[sje(rename = "position")]
+    Position(PositionEvent),
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct PositionEvent {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+#[test]
+fn should_return_error_from_try_accessors() {
+    let account = AccountDecoder::decode(br#"{"E":NaN,"l":-1,"m":{"s":"btcusdt","a":1},"p":[],"i":[]}"#).unwrap();
+    assert!(matches!(account.try_event_time(), Err(Error::Parse(value)) if value == "NaN"));
+    assert!(matches!(account.try_event_time_as_f64(), Ok(value) if value.is_nan()));
+    assert!(matches!(account.try_limit(), Err(Error::Parse(_))));
+    assert!(matches!(Account::try_from(&account), Err(Error::Parse(_))));
+
+    let account = AccountDecoder::decode(br#"{"E":1,"m":{"s":"btcusdt","a":1},"p":[],"i":[]}"#).unwrap();
+    assert_eq!(1, account.try_event_time().unwrap());
+    assert_eq!(1.0, account.try_event_time_as_f64().unwrap());
+    assert_eq!(None, account.try_limit().unwrap());
+}
+
+#[test]
+fn should_try_convert_to_owned() {
+    let account =
+        AccountDecoder::decode(br#"{"E":1,"l":5,"m":{"s":"btcusdt","a":1},"p":[{"s":"ethusdt","a":2}],"i":[7,8]}"#)
+            .unwrap();
+    assert_eq!(
+        Account {
+            event_time: 1,
+            limit: Some(5),
+            main: Position {
+                symbol: "btcusdt".to_string(),
+                amount: 1
+            },
+            positions: vec![Position {
+                symbol: "ethusdt".to_string(),
+                amount: 2
+            }],
+            ids: vec![7, 8],
+        },
+        Account::try_from(&account).unwrap()
+    );
+
+    let account =
+        AccountDecoder::decode(br#"{"E":1,"m":{"s":"btcusdt","a":1},"p":[{"s":"ethusdt","a":-2}],"i":[]}"#).unwrap();
+    assert!(matches!(Account::try_from(&account), Err(Error::Parse(value)) if value == "-2"));
+
+    let account = AccountDecoder::decode(br#"{"E":1,"m":{"s":"btcusdt","a":1},"p":[],"i":[7,x]}"#).unwrap();
+    assert!(matches!(Account::try_from(&account), Err(Error::Parse(value)) if value == "x"));
+}
+
+#[test]
+fn should_try_convert_array_and_union() {
+    let positions = PortfolioDecoder::decode(br#"[{"s":"btcusdt","a":1}]"#).unwrap();
+    assert_eq!(
+        Portfolio(vec![Position {
+            symbol: "btcusdt".to_string(),
+            amount: 1
+        }]),
+        Portfolio::try_from(&positions).unwrap()
+    );
+    let positions = PortfolioDecoder::decode(br#"[{"s":"btcusdt","a":true}]"#).unwrap();
+    assert!(Portfolio::try_from(&positions).is_err());
+
+    let event = EventDecoder::decode(br#"{"e":"position","a":3}"#).unwrap();
+    assert_eq!(
+        Event::Position(PositionEvent {
+            event_type: "position".to_string(),
+            amount: 3
+        }),
+        Event::try_from(&event).unwrap()
+    );
+    let event = EventDecoder::decode(br#"{"e":"position","a":3.5}"#).unwrap();
+    assert!(Event::try_from(&event).is_err());
+}
@@ -0,0 +1,101 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Price(f64);
+
+impl FromStr for Price {
+    type Err = <f64 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+pub struct Position {
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "a")]
+    amount: u32,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(object)]
+#[allow(dead_code)]
+pub struct Book {
+    #[sje(rename = "b")]
+    bids: Vec<(Price, Price)>,
+    #[sje(rename = "i")]
+    ids: Vec<u64>,
+}
+
+#[derive(Decoder, Debug)]
+#[sje(array)]
+#[allow(dead_code)]
+pub struct Positions(#[sje(decoder = true)] Vec<Position>);
+
+#[test]
+fn should_yield_all_elements() {
+    let book = BookDecoder::decode(br#"{"b":[["1.5","2.5"],["3.5","4.5"]],"i":[1,2]}"#).unwrap();
+    let bids = book.bids().try_iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(vec![(Price(1.5), Price(2.5)), (Price(3.5), Price(4.5))], bids);
+    let ids = book.ids().try_iter().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(vec![1, 2], ids);
+
+    let positions = PositionsDecoder::decode(br#"[{"s":"btcusdt","a":100},{"s":"ethusdt","a":200}]"#).unwrap();
+    let mut iter = positions.try_iter();
+    assert_eq!(100, iter.next().unwrap().unwrap().amount());
+    assert_eq!(200, iter.next().unwrap().unwrap().amount());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn should_stop_after_first_malformed_element() {
+    let book = BookDecoder::decode(br#"{"b":[["1.5","2.5"],["NaN?","4.5"],["5.5","6.5"]],"i":[1,x,3]}"#).unwrap();
+
+    let mut bids = book.bids().try_iter();
+    assert_eq!((Price(1.5), Price(2.5)), bids.next().unwrap().unwrap());
+    assert!(matches!(bids.next(), Some(Err(Error::Parse(value))) if value == "NaN?"));
+    assert!(bids.next().is_none());
+
+    let mut ids = book.ids().try_iter();
+    assert_eq!(1, ids.next().unwrap().unwrap());
+    assert!(matches!(ids.next(), Some(Err(Error::Parse(_)))));
+    assert!(ids.next().is_none());
+}
+
+#[test]
+fn should_report_missing_tuple_element() {
+    let book = BookDecoder::decode(br#"{"b":[["1.5"]],"i":[]}"#).unwrap();
+    let mut bids = book.bids().try_iter();
+    assert!(matches!(bids.next(), Some(Err(Error::Other(_)))));
+    assert!(bids.next().is_none());
+    assert!(book.ids().try_iter().next().is_none());
+
+    let book = BookDecoder::decode(br#"{"b":[["1.5","2.5"],["1.5"],["2.5","3"]],"i":[]}"#).unwrap();
+    let mut bids = book.bids().try_iter();
+    assert_eq!((Price(1.5), Price(2.5)), bids.next().unwrap().unwrap());
+    assert!(matches!(bids.next(), Some(Err(Error::Other(_)))));
+    assert!(bids.next().is_none());
+}
+
+#[test]
+fn should_record_first_error() {
+    let book = BookDecoder::decode(br#"{"b":[["1.5","2.5"],["1.5"],["2.5","3"]],"i":[1,2]}"#).unwrap();
+    let mut bids = book.bids().try_iter().record_errors();
+    let mut count = 0;
+    for (price, _) in bids.by_ref() {
+        assert_eq!(Price(1.5), price);
+        count += 1;
+    }
+    assert_eq!(1, count);
+    assert!(matches!(bids.error(), Some(Error::Other(_))));
+
+    let mut ids = book.ids().try_iter().record_errors();
+    assert_eq!(vec![1, 2], ids.by_ref().collect::<Vec<_>>());
+    assert!(ids.error().is_none());
+}
@@ -0,0 +1,79 @@
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(tuple)]
+pub struct Kline {
+    open_time: u64,
+    open: String,
+    high: String,
+    low: String,
+    close: String,
+    volume: String,
+    close_time: u64,
+    quote_volume: String,
+    trades: u64,
+    taker_base_volume: String,
+    taker_quote_volume: String,
+    #[sje(len = 1)]
+    ignore: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(tuple)]
+pub struct Fill {
+    price: f64,
+    is_maker: bool,
+    fee: Option<String>,
+}
+
+const KLINE: &[u8] = br#"[1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]"#;
+
+#[test]
+fn should_decode_tuple() {
+    let kline = KlineDecoder::decode(KLINE).unwrap();
+    assert_eq!(1499040000000, kline.open_time());
+    assert_eq!("0.01634790", kline.open_as_str());
+    assert_eq!(b"0.80000000", kline.high_as_slice());
+    assert_eq!("0.01575800", kline.low());
+    assert_eq!("0.01577100", kline.close());
+    assert_eq!("148976.11427815", kline.volume());
+    assert_eq!(&1499644799999, kline.close_time_as_lazy_field().get_ref().unwrap());
+    assert_eq!("2434.19055334", kline.quote_volume());
+    assert_eq!(308, kline.trades());
+    assert_eq!("1756.87402397", kline.taker_base_volume());
+    assert_eq!("28.46694368", kline.taker_quote_volume());
+    assert_eq!("0", kline.ignore());
+}
+
+#[test]
+fn should_convert_tuple_to_owned_and_encode() {
+    let kline: Kline = KlineDecoder::decode(KLINE).unwrap().into();
+    assert_eq!(308, kline.trades);
+    assert_eq!("0.01577100", kline.close);
+
+    let mut bytes = Vec::new();
+    KlineEncoder::encode_to_vec(&kline, &mut bytes).unwrap();
+    assert_eq!(KLINE, bytes.as_slice());
+}
+
+#[test]
+fn should_decode_tuple_with_null_and_booleans() {
+    let fill = FillDecoder::decode(br#"[95732.6,true,null]"#).unwrap();
+    assert_eq!(95732.6, fill.price());
+    assert!(fill.is_maker());
+    assert_eq!(None, fill.fee());
+
+    let fill: Fill = FillDecoder::decode(br#"[95732.6,false,"0.01"]"#).unwrap().into();
+    assert_eq!(
+        Fill {
+            price: 95732.6,
+            is_maker: false,
+            fee: Some("0.01".to_string()),
+        },
+        fill
+    );
+
+    let mut bytes = Vec::new();
+    FillEncoder::encode_to_vec(&Fill { fee: None, ..fill }, &mut bytes).unwrap();
+    assert_eq!(br#"[95732.6,false,null]"#, bytes.as_slice());
+}
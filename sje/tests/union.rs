@@ -0,0 +1,122 @@
+use sje::error::Error;
+use sje_derive::{Decoder, Encoder};
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object)]
+pub struct ExecutionReport {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "E")]
+    event_time: u64,
+    #[sje(rename = "s")]
+    symbol: String,
+    #[sje(rename = "X")]
+    order_status: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(object, fallback)]
+pub struct ListenKeyExpired {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "E")]
+    event_time: u64,
+    #[sje(rename = "listenKey")]
+    listen_key: String,
+}
+
+#[derive(Decoder, Encoder, Debug, PartialEq)]
+#[sje(union, tag = "e")]
+pub enum UserData {
+    #[sje(rename = "executionReport")]
+    ExecutionReport(ExecutionReport),
+    #[sje(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpired),
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object, whitespace = "tolerant")]
+pub struct Trade {
+    #[sje(rename = "e")]
+    event_type: String,
+    #[sje(rename = "p")]
+    price: String,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(union, tag = "e", whitespace = "tolerant")]
+pub enum MarketData {
+    #[sje(rename = "trade")]
+    Trade(Trade),
+}
+
+#[test]
+fn should_decode_variant_by_tag() {
+    let json = br#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","X":"NEW"}"#;
+    match UserDataDecoder::decode(json).unwrap() {
+        UserDataDecoder::ExecutionReport(report) => {
+            assert_eq!(1499405658658, report.event_time());
+            assert_eq!("ETHBTC", report.symbol_as_str());
+            assert_eq!("NEW", report.order_status_as_str());
+        }
+        other => panic!("unexpected variant {other:?}"),
+    }
+
+    let json = br#"{"e":"listenKeyExpired","E":1576653824250,"listenKey":"OfYGbUzi3PraNagEkdKuFwUHn48brFsItTdsuiIXrucEvD0rhRXZ7I6URWfE8YE8"}"#;
+    match UserDataDecoder::decode(json).unwrap() {
+        UserDataDecoder::ListenKeyExpired(expired) => assert_eq!(1576653824250, expired.event_time()),
+        other => panic!("unexpected variant {other:?}"),
+    }
+}
+
+#[test]
+fn should_decode_variant_when_tag_is_not_first() {
+    let json = br#"{"E":1576653824250,"listenKey":"abc","e":"listenKeyExpired"}"#;
+    assert_eq!(b"listenKeyExpired", UserDataDecoder::tag(json).unwrap());
+
+    let user_data: UserData = UserDataDecoder::decode(json).unwrap().into();
+    assert_eq!(
+        UserData::ListenKeyExpired(ListenKeyExpired {
+            event_type: "listenKeyExpired".to_string(),
+            event_time: 1576653824250,
+            listen_key: "abc".to_string(),
+        }),
+        user_data
+    );
+}
+
+#[test]
+fn should_decode_variant_with_whitespace() {
+    let json = br#"{"e": "trade", "p": "1.5"}"#;
+    assert_eq!(b"trade", MarketDataDecoder::tag(json).unwrap());
+    let MarketDataDecoder::Trade(trade) = MarketDataDecoder::decode(json).unwrap();
+    assert_eq!("1.5", trade.price());
+
+    let json = b"{ \"p\" : \"1.5\" ,\n \"e\" : \"trade\" }";
+    assert_eq!(b"trade", MarketDataDecoder::tag(json).unwrap());
+}
+
+#[test]
+fn should_fail_on_unknown_or_missing_tag() {
+    let json = br#"{"e":"outboundAccountPosition","E":1564034571105}"#;
+    assert!(
+        matches!(UserDataDecoder::decode(json), Err(Error::UnknownVariant(tag)) if tag == "outboundAccountPosition")
+    );
+
+    let json = br#"{"E":1564034571105}"#;
+    assert!(matches!(UserDataDecoder::decode(json), Err(Error::MissingField("e"))));
+}
+
+#[test]
+fn should_encode_variant() {
+    let user_data = UserData::ExecutionReport(ExecutionReport {
+        event_type: "executionReport".to_string(),
+        event_time: 1499405658658,
+        symbol: "ETHBTC".to_string(),
+        order_status: "NEW".to_string(),
+    });
+    let mut bytes = Vec::new();
+    UserDataEncoder::encode_to_vec(&user_data, &mut bytes).unwrap();
+    assert_eq!(br#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","X":"NEW"}"#, bytes.as_slice());
+    assert_eq!(user_data, UserDataDecoder::decode(&bytes).unwrap().into());
+}
@@ -0,0 +1,130 @@
+use sje::error::Error;
+use sje_derive::Decoder;
+
+#[derive(Decoder, Debug)]
+#[sje(object, whitespace = "tolerant")]
+#[allow(dead_code)]
+struct ListenKeyExpired {
+    #[sje(rename = "e", len = 16)]
+    event_type: String,
+    #[sje(rename = "E", ty = "string")]
+    event_time: u64,
+    #[sje(rename = "listenKey")]
+    listen_key: String,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(object, verify_keys, whitespace = "tolerant")]
+struct Depth {
+    #[sje(rename = "lastUpdateId")]
+    last_update_id: u64,
+    #[sje(rename = "pu")]
+    previous_update_id: Option<u64>,
+    #[sje(rename = "bids")]
+    bids: Vec<(String, String)>,
+    #[sje(rename = "T", decoder = true)]
+    top: Level,
+    #[sje(rename = "m")]
+    is_snapshot: bool,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(tuple, whitespace = "tolerant")]
+struct Level {
+    price: String,
+    quantity: f64,
+    orders: Option<u32>,
+}
+
+#[derive(Decoder, Debug, PartialEq)]
+#[sje(array, whitespace = "tolerant")]
+struct Levels(#[sje(decoder = true)] Vec<Level>);
+
+#[test]
+fn should_decode_with_whitespace_around_values() {
+    let json = br#"{"e": "listenKeyExpired", "E": "1743606297156", "listenKey": "FdffIUjdfd343DtLMw2tKS87iL2HpYRniDWpkoxWCb4fwP2yzJXalBlBNnz471cE"}"#;
+    let listen_key_expired = ListenKeyExpiredDecoder::decode(json).unwrap();
+    assert_eq!("listenKeyExpired", listen_key_expired.event_type());
+    assert_eq!(1743606297156, listen_key_expired.event_time());
+    assert_eq!("FdffIUjdfd343DtLMw2tKS87iL2HpYRniDWpkoxWCb4fwP2yzJXalBlBNnz471cE", listen_key_expired.listen_key());
+}
+
+#[test]
+fn should_decode_pretty_printed_object() {
+    let json = br#"{
+  "lastUpdateId" : 1027024 ,
+  "bids": [
+    [ "4.00000000", "431.00000000" ],
+    ["4.00000100","12.00000000"]
+  ],
+  "T": [ "4.1", 2.5 , null ],
+  "m": true
+}"#;
+    let depth = DepthDecoder::decode(json).unwrap();
+    assert_eq!(1027024, depth.last_update_id());
+    assert_eq!(None, depth.previous_update_id());
+    assert_eq!(2, depth.bids_count());
+    assert_eq!("4.1", depth.top().price());
+    assert_eq!(2.5, depth.top().quantity());
+    assert!(depth.is_snapshot());
+
+    let depth: Depth = depth.into();
+    assert_eq!(
+        vec![
+            ("4.00000000".to_string(), "431.00000000".to_string()),
+            ("4.00000100".to_string(), "12.00000000".to_string())
+        ],
+        depth.bids
+    );
+}
+
+#[test]
+fn should_ignore_extra_tuple_elements_with_whitespace() {
+    let json = br#"{ "lastUpdateId": 1, "bids": [ ["4.00000100","12.00000000", "0" ], [ "4.2" , "1.5" ,"3"] ], "T": ["1", 1, null], "m": true }"#;
+    let depth: Depth = DepthDecoder::decode(json).unwrap().into();
+    assert_eq!(
+        vec![
+            ("4.00000100".to_string(), "12.00000000".to_string()),
+            ("4.2".to_string(), "1.5".to_string())
+        ],
+        depth.bids
+    );
+    assert!(depth.is_snapshot);
+}
+
+#[test]
+fn should_decode_present_optional_field_with_whitespace() {
+    let json = br#"{ "lastUpdateId": 1, "pu" : 0, "bids": [], "T": ["1", 1, 3], "m": false }"#;
+    let depth = DepthDecoder::decode(json).unwrap();
+    assert_eq!(Some(0), depth.previous_update_id());
+    assert_eq!(0, depth.bids_count());
+    assert_eq!(Some(3), depth.top().orders());
+    assert!(!depth.is_snapshot());
+}
+
+#[test]
+fn should_reject_unexpected_key_with_whitespace() {
+    let json = br#"{ "lastUpdateId": 1, "asks": [], "T": ["1", 1, 3], "m": false }"#;
+    assert!(matches!(DepthDecoder::decode(json), Err(Error::UnexpectedKey { expected: "bids", .. })));
+}
+
+#[test]
+fn should_decode_pretty_printed_array() {
+    let json = b"[\n  [\"4.1\", 2.5, 7],\n  [\"4.2\", 1, null]\n]\n";
+    let levels: Levels = LevelsDecoder::decode(json).unwrap().into();
+    assert_eq!(
+        Levels(vec![
+            Level {
+                price: "4.1".to_string(),
+                quantity: 2.5,
+                orders: Some(7),
+            },
+            Level {
+                price: "4.2".to_string(),
+                quantity: 1.0,
+                orders: None,
+            },
+        ]),
+        levels
+    );
+}
@@ -0,0 +1,373 @@
+use crate::{
+    SjeAttribute, SjeFieldAttribute, SjeType, array_field, option_inner_type, resolve_type, sje_field_attribute,
+    tuple_element_types, union_variants, vec_element_type,
+};
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::{format_ident, quote};
+use syn::{DataEnum, DataStruct, Fields, Ident, Type};
+
+pub(crate) fn handle_struct(name: &Ident, data_struct: DataStruct, sje_attr: SjeAttribute) -> TokenStream {
+    match sje_attr.sje_type {
+        SjeType::Object => handle_sje_object(name, data_struct),
+        SjeType::Array => handle_sje_array(name, data_struct),
+        SjeType::Tuple => handle_sje_tuple(name, data_struct),
+        SjeType::Union => quote! { compile_error!("union can only be derived on an enum."); }.into(),
+    }
+}
+
+/// Each variant struct writes its own tag field.
+pub(crate) fn handle_union(name: &Ident, data_enum: DataEnum, sje_attr: SjeAttribute) -> TokenStream {
+    if !matches!(sje_attr.sje_type, SjeType::Union) {
+        return quote! { compile_error!("enum can only be derived as `union`."); }.into();
+    }
+    let variants = match union_variants(&data_enum) {
+        Ok(variants) => variants,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let arms = variants.iter().map(|(variant, _, _)| {
+        quote! {
+            #name::#variant(value) => sje::encoder::Encode::encode(value, buffer)
+        }
+    });
+
+    let encoder_struct = encoder_struct(name);
+    let generated = quote! {
+        impl sje::encoder::Encode for #name {
+            #[inline]
+            fn encode<B: sje::encoder::Buffer>(&self, buffer: &mut B) -> Result<(), sje::error::Error> {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        #encoder_struct
+    };
+
+    generated.into()
+}
+
+fn handle_sje_object(name: &Ident, data_struct: DataStruct) -> TokenStream {
+    let fields = match data_struct.fields {
+        Fields::Named(fields) => fields.named,
+        _ => return quote! { compile_error!("Encoder can only be derived for structs with named fields."); }.into(),
+    };
+    // skipped keys are not written
+    let fields = fields
+        .iter()
+        .filter(|field| !sje_field_attribute(field).skip)
+        .collect::<Vec<_>>();
+
+    // optional fields make the separator depend on whether anything was written before them
+    let mut field_writes = Vec::with_capacity(fields.len());
+    let mut required_written = false;
+    for (index, field) in fields.iter().enumerate() {
+        let field_name = field.ident.as_ref().unwrap();
+        let sje_field = sje_field_attribute(field);
+        let key = sje_field.name.clone().unwrap_or_else(|| field_name.to_string());
+        let optional = option_inner_type(&field.ty).is_some() && !sje_field.nullable;
+        let has_next = index + 1 < fields.len();
+
+        let (separator, key) = match (index, required_written) {
+            (0, _) if optional => {
+                field_writes.push(match has_next {
+                    true => quote! {
+                        buffer.put(b"{")?;
+                        let mut empty = true;
+                    },
+                    false => quote!(buffer.put(b"{")?;),
+                });
+                (quote!(), format!("\"{key}\":"))
+            }
+            (0, _) => (quote!(), format!("{{\"{key}\":")),
+            (_, true) => (quote!(), format!(",\"{key}\":")),
+            (_, false) => (
+                quote! {
+                    if !empty {
+                        buffer.put(b",")?;
+                    }
+                },
+                format!("\"{key}\":"),
+            ),
+        };
+        let key = Literal::byte_string(key.as_bytes());
+
+        match option_inner_type(&field.ty) {
+            Some(inner_type) if sje_field.nullable => {
+                let value = encode_value(quote!(value), inner_type, &sje_field);
+                field_writes.push(quote! {
+                    #separator
+                    buffer.put(#key)?;
+                    match &self.#field_name {
+                        Some(value) => {
+                            #value
+                        }
+                        None => buffer.put(b"null")?,
+                    }
+                });
+                required_written = true;
+            }
+            Some(inner_type) => {
+                let value = encode_value(quote!(value), inner_type, &sje_field);
+                let mark_written = match !required_written && has_next {
+                    true => quote!(empty = false;),
+                    false => quote!(),
+                };
+                field_writes.push(quote! {
+                    if let Some(value) = &self.#field_name {
+                        #separator
+                        buffer.put(#key)?;
+                        #value
+                        #mark_written
+                    }
+                });
+            }
+            None => {
+                let value = encode_value(quote!(self.#field_name), &field.ty, &sje_field);
+                field_writes.push(quote! {
+                    #separator
+                    buffer.put(#key)?;
+                    #value
+                });
+                required_written = true;
+            }
+        }
+    }
+
+    // empty struct still needs the opening brace
+    let close = if fields.is_empty() {
+        quote!(buffer.put(b"{}"))
+    } else {
+        quote!(buffer.put(b"}"))
+    };
+
+    let encoder_struct = encoder_struct(name);
+    let generated = quote! {
+        impl sje::encoder::Encode for #name {
+            #[inline]
+            fn encode<B: sje::encoder::Buffer>(&self, buffer: &mut B) -> Result<(), sje::error::Error> {
+                #(#field_writes)*
+                #close
+            }
+        }
+
+        #encoder_struct
+    };
+
+    generated.into()
+}
+
+fn handle_sje_tuple(name: &Ident, data_struct: DataStruct) -> TokenStream {
+    let fields = match data_struct.fields {
+        Fields::Named(fields) => fields.named,
+        _ => return quote! { compile_error!("Encoder can only be derived for structs with named fields."); }.into(),
+    };
+
+    let element_writes = fields.iter().enumerate().map(|(index, field)| {
+        let field_name = field.ident.as_ref().unwrap();
+        let sje_field = sje_field_attribute(field);
+        let separator = match index {
+            0 => quote!(buffer.put(b"[")?;),
+            _ => quote!(buffer.put(b",")?;),
+        };
+        match option_inner_type(&field.ty) {
+            // skipped element still takes up its position
+            _ if sje_field.skip => quote! {
+                #separator
+                buffer.put(b"null")?;
+            },
+            Some(inner_type) => {
+                let value = encode_value(quote!(value), inner_type, &sje_field);
+                quote! {
+                    #separator
+                    match &self.#field_name {
+                        Some(value) => {
+                            #value
+                        }
+                        None => buffer.put(b"null")?,
+                    }
+                }
+            }
+            None => {
+                let value = encode_value(quote!(self.#field_name), &field.ty, &sje_field);
+                quote! {
+                    #separator
+                    #value
+                }
+            }
+        }
+    });
+
+    // empty tuple still needs the opening bracket
+    let close = if fields.is_empty() {
+        quote!(buffer.put(b"[]"))
+    } else {
+        quote!(buffer.put(b"]"))
+    };
+
+    let encoder_struct = encoder_struct(name);
+    let generated = quote! {
+        impl sje::encoder::Encode for #name {
+            #[inline]
+            fn encode<B: sje::encoder::Buffer>(&self, buffer: &mut B) -> Result<(), sje::error::Error> {
+                #(#element_writes)*
+                #close
+            }
+        }
+
+        #encoder_struct
+    };
+
+    generated.into()
+}
+
+fn handle_sje_array(name: &Ident, data_struct: DataStruct) -> TokenStream {
+    let field = match array_field(&data_struct.fields) {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let sje_field = sje_field_attribute(field);
+    let value = match &field.ident {
+        Some(field_name) => quote!(self.#field_name),
+        None => quote!(self.0),
+    };
+    let value = encode_value(value, &field.ty, &sje_field);
+
+    let encoder_struct = encoder_struct(name);
+    let generated = quote! {
+        impl sje::encoder::Encode for #name {
+            #[inline]
+            fn encode<B: sje::encoder::Buffer>(&self, buffer: &mut B) -> Result<(), sje::error::Error> {
+                #value
+                Ok(())
+            }
+        }
+
+        #encoder_struct
+    };
+
+    generated.into()
+}
+
+fn encoder_struct(name: &Ident) -> proc_macro2::TokenStream {
+    let encoder_name = format_ident!("{}Encoder", name);
+    quote! {
+        pub struct #encoder_name;
+
+        impl #encoder_name {
+            /// Encode into the provided slice, returning number of bytes written.
+            #[inline]
+            pub fn encode(value: &#name, bytes: &mut [u8]) -> Result<usize, sje::error::Error> {
+                let mut buffer = sje::encoder::SliceBuffer::wrap(bytes);
+                sje::encoder::Encode::encode(value, &mut buffer)?;
+                Ok(buffer.position())
+            }
+
+            /// Append to the provided vector, returning number of bytes written.
+            #[inline]
+            pub fn encode_to_vec(value: &#name, bytes: &mut Vec<u8>) -> Result<usize, sje::error::Error> {
+                let start = bytes.len();
+                sje::encoder::Encode::encode(value, bytes)?;
+                Ok(bytes.len() - start)
+            }
+        }
+    }
+}
+
+fn encode_value(value: proc_macro2::TokenStream, ty: &Type, sje_field: &SjeFieldAttribute) -> proc_macro2::TokenStream {
+    if sje_field.decoder && vec_element_type(ty).is_none() {
+        return quote! {
+            sje::encoder::Encode::encode(&#value, buffer)?;
+        };
+    }
+    match resolve_type(ty, sje_field.ty.clone()) {
+        Ok("string") => quote! {
+            sje::encoder::write_quoted(buffer, &#value)?;
+        },
+        Ok("number") | Ok("boolean") => quote! {
+            sje::encoder::write_value(buffer, &#value)?;
+        },
+        Ok("object") => quote! {
+            sje::encoder::Encode::encode(&#value, buffer)?;
+        },
+        Ok("array") => match vec_element_type(ty) {
+            Some(element_type) => {
+                let element = encode_element(element_type, sje_field);
+                quote! {
+                    buffer.put(b"[")?;
+                    for (index, item) in #value.iter().enumerate() {
+                        if index > 0 {
+                            buffer.put(b",")?;
+                        }
+                        #element
+                    }
+                    buffer.put(b"]")?;
+                }
+            }
+            None => syn::Error::new_spanned(ty, "array must be declared as Vec<T>").to_compile_error(),
+        },
+        Ok(other) => syn::Error::new_spanned(ty, format!("unsupported json type '{}'", other)).to_compile_error(),
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn encode_element(ty: &Type, sje_field: &SjeFieldAttribute) -> proc_macro2::TokenStream {
+    if sje_field.decoder {
+        return quote! {
+            sje::encoder::Encode::encode(item, buffer)?;
+        };
+    }
+    match ty {
+        Type::Tuple(tuple) => {
+            let element_types = match tuple_element_types(tuple, sje_field.ty.clone(), sje_field.remainder) {
+                Ok(element_types) => element_types,
+                Err(e) => return e.to_compile_error(),
+            };
+            // remainder is written as is, it already holds the separated elements
+            let rest = match sje_field.remainder {
+                true => {
+                    let index = syn::Index::from(element_types.len());
+                    quote! {
+                        let rest = item.#index.to_string();
+                        if !rest.is_empty() {
+                            buffer.put(b",")?;
+                            buffer.put(rest.as_bytes())?;
+                        }
+                    }
+                }
+                false => quote!(),
+            };
+            let elements = element_types.into_iter().enumerate().map(|(index, type_str)| {
+                let index = syn::Index::from(index);
+                let separator = if index.index == 0 {
+                    quote!()
+                } else {
+                    quote!(buffer.put(b",")?;)
+                };
+                let write = match type_str {
+                    "string" => quote!(write_quoted),
+                    _ => quote!(write_value),
+                };
+                quote! {
+                    #separator
+                    sje::encoder::#write(buffer, &item.#index)?;
+                }
+            });
+            quote! {
+                buffer.put(b"[")?;
+                #(#elements)*
+                #rest
+                buffer.put(b"]")?;
+            }
+        }
+        _ => match resolve_type(ty, sje_field.ty.clone()) {
+            Ok("number") | Ok("boolean") => quote! {
+                sje::encoder::write_value(buffer, item)?;
+            },
+            _ => quote! {
+                sje::encoder::write_quoted(buffer, item)?;
+            },
+        },
+    }
+}
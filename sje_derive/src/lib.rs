@@ -1,15 +1,17 @@
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Literal, Span};
 use quote::{format_ident, quote};
 use std::str::FromStr;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 use syn::{
-    Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Ident, LitBool, LitInt, LitStr, PathArguments, PathSegment,
-    Token, Type, TypePath, parse_macro_input,
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Field, Fields, Ident, LitBool, LitInt, LitStr,
+    PathArguments, PathSegment, Token, Type, TypePath, TypeTuple, Variant, parse_macro_input,
 };
 
+mod encoder;
+
 #[derive(Debug, Copy, Clone)]
 enum SjeType {
     Object,
@@ -32,20 +34,75 @@ impl FromStr for SjeType {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 struct SjeAttribute {
     sje_type: SjeType,
+    /// compare expected key at each position
+    verify_keys: bool,
+    /// fall back to key lookup when the keys are out of order
+    fallback: bool,
+    /// key of the union discriminator
+    tag: Option<String>,
+    /// skip any whitespace around the keys, values and separators
+    tolerant: bool,
+    /// generate decoder that locates each field on first access
+    lazy: bool,
 }
 
 impl Parse for SjeAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident: Ident = input.parse()?;
         let sje_type = ident.to_string().parse()?;
-        Ok(SjeAttribute { sje_type })
+        let mut verify_keys = false;
+        let mut fallback = false;
+        let mut tag = None;
+        let mut tolerant = false;
+        let mut lazy = false;
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse()?;
+            if ident == "verify_keys" {
+                verify_keys = true;
+            } else if ident == "fallback" {
+                fallback = true;
+            } else if ident == "tag" {
+                input.parse::<Token![=]>()?;
+                let tag_lit: LitStr = input.parse()?;
+                tag = Some(tag_lit.value());
+            } else if ident == "lazy" {
+                lazy = true;
+            } else if ident == "whitespace" {
+                input.parse::<Token![=]>()?;
+                let whitespace_lit: LitStr = input.parse()?;
+                tolerant = match whitespace_lit.value().as_str() {
+                    "tolerant" => true,
+                    "strict" => false,
+                    _ => return Err(syn::Error::new_spanned(whitespace_lit, "expected 'strict' or 'tolerant'")),
+                };
+            } else {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "expected ['verify_keys' | 'fallback' | 'tag' | 'whitespace' | 'lazy']",
+                ));
+            }
+        }
+
+        Ok(SjeAttribute {
+            sje_type,
+            verify_keys,
+            fallback,
+            tag,
+            tolerant,
+            lazy,
+        })
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 struct SjeFieldAttribute {
     #[allow(dead_code)]
     /// value length
@@ -59,6 +116,14 @@ struct SjeFieldAttribute {
     /// offset at which value begins
     offset: usize,
     decoder: bool,
+    /// value can be `null`
+    nullable: bool,
+    /// value is skipped without being stored
+    skip: bool,
+    /// generate decoder that stops after this field
+    until: bool,
+    /// last tuple element captures any elements that follow
+    remainder: bool,
 }
 
 impl Parse for SjeFieldAttribute {
@@ -69,6 +134,10 @@ impl Parse for SjeFieldAttribute {
         let mut also_as = None;
         let mut offset = 0;
         let mut decoder = false;
+        let mut nullable = false;
+        let mut skip = false;
+        let mut until = false;
+        let mut remainder = false;
 
         while !input.is_empty() {
             let lookahead = input.lookahead1();
@@ -98,8 +167,19 @@ impl Parse for SjeFieldAttribute {
                     input.parse::<Token![=]>()?;
                     let decoder_lit: LitBool = input.parse()?;
                     decoder = decoder_lit.value();
+                } else if ident == "nullable" {
+                    nullable = true;
+                } else if ident == "skip" {
+                    skip = true;
+                } else if ident == "until" {
+                    until = true;
+                } else if ident == "remainder" {
+                    remainder = true;
                 } else {
-                    return Err(syn::Error::new_spanned(ident, "expected ['len' | 'rename' | 'ty']"));
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        "expected ['len' | 'rename' | 'ty' | 'also_as' | 'offset' | 'decoder' | 'nullable' | 'skip' | 'until' | 'remainder']",
+                    ));
                 }
             } else {
                 return Err(lookahead.error());
@@ -118,24 +198,93 @@ impl Parse for SjeFieldAttribute {
             also_as,
             offset,
             decoder,
+            nullable,
+            skip,
+            until,
+            remainder,
         })
     }
 }
 
-#[proc_macro_derive(Decoder, attributes(sje))]
-pub fn decoder_derive(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+#[derive(Debug, Clone, Default)]
+struct SjeVariantAttribute {
+    /// variant name override
+    name: Option<String>,
+    /// additional names the variant is recognised by
+    aliases: Vec<String>,
+    /// catch-all for unrecognised names
+    other: bool,
+}
 
-    let sje_attr = ast
-        .attrs
+impl Parse for SjeVariantAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+        let mut aliases = Vec::new();
+        let mut other = false;
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "rename" {
+                input.parse::<Token![=]>()?;
+                let ref_lit: LitStr = input.parse()?;
+                name = Some(ref_lit.value());
+            } else if ident == "alias" {
+                input.parse::<Token![=]>()?;
+                let alias_lit: LitStr = input.parse()?;
+                aliases.push(alias_lit.value());
+            } else if ident == "other" {
+                other = true;
+            } else {
+                return Err(syn::Error::new_spanned(ident, "expected ['rename' | 'alias' | 'other']"));
+            }
+
+            // Optional comma
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(SjeVariantAttribute { name, aliases, other })
+    }
+}
+
+fn sje_attribute(attrs: &[Attribute]) -> Option<SjeAttribute> {
+    attrs
         .iter()
         .find(|attr| attr.path().is_ident("sje"))
         .map(|attr| attr.parse_args::<SjeAttribute>())
         .transpose()
-        .expect("Failed to parse 'sje' attribute");
+        .expect("Failed to parse 'sje' attribute")
+}
+
+fn sje_field_attribute(field: &Field) -> SjeFieldAttribute {
+    field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("sje"))
+        .map(|attr| attr.parse_args::<SjeFieldAttribute>().expect("unable to parse"))
+        .unwrap_or_default()
+}
+
+fn sje_variant_attribute(variant: &Variant) -> SjeVariantAttribute {
+    variant
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("sje"))
+        .map(|attr| attr.parse_args::<SjeVariantAttribute>().expect("unable to parse"))
+        .unwrap_or_default()
+}
+
+#[proc_macro_derive(Decoder, attributes(sje))]
+pub fn decoder_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let sje_attr = sje_attribute(&ast.attrs);
 
     match ast.data {
-        Data::Enum(data_enum) => handle_enum(&ast.ident, data_enum),
+        Data::Enum(data_enum) => match sje_attr {
+            Some(sje_attr) => handle_union(&ast.ident, data_enum, sje_attr),
+            None => handle_enum(&ast.ident, data_enum),
+        },
         Data::Struct(data_struct) => {
             handle_struct(&ast.ident, data_struct, sje_attr.expect("sje attribute must be present"))
         }
@@ -143,107 +292,513 @@ pub fn decoder_derive(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(Encoder, attributes(sje))]
+pub fn encoder_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let sje_attr = sje_attribute(&ast.attrs);
+
+    match ast.data {
+        Data::Struct(data_struct) => {
+            encoder::handle_struct(&ast.ident, data_struct, sje_attr.expect("sje attribute must be present"))
+        }
+        Data::Enum(data_enum) => {
+            encoder::handle_union(&ast.ident, data_enum, sje_attr.expect("sje attribute must be present"))
+        }
+        _ => panic!("Encoder can only be derived for enums and structs"),
+    }
+}
+
+/// Unit enum parsed from the json string value, e.g. order side.
 fn handle_enum(name: &syn::Ident, data_enum: DataEnum) -> TokenStream {
-    let variants = data_enum.variants.iter().map(|v| &v.ident);
+    let mut arms = Vec::with_capacity(data_enum.variants.len());
+    let mut other = None;
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Error::new_spanned(variant, "enum variant must not have any fields")
+                .to_compile_error()
+                .into();
+        }
+        let ident = &variant.ident;
+        let sje_variant = sje_variant_attribute(variant);
+        if sje_variant.other {
+            if other.is_some() {
+                return Error::new_spanned(variant, "only one variant can be marked as `other`")
+                    .to_compile_error()
+                    .into();
+            }
+            other = Some(ident);
+            continue;
+        }
+        let names = std::iter::once(sje_variant.name.unwrap_or_else(|| ident.to_string()))
+            .chain(sje_variant.aliases)
+            .map(|name| Literal::byte_string(name.as_bytes()));
+        arms.push(quote!(#(#names)|* => Ok(#name::#ident)));
+    }
+
+    let fallback = match other {
+        Some(other) => quote!(_ => Ok(#name::#other)),
+        None => quote!(other => Err(sje::error::Error::unknown_variant(other))),
+    };
+
+    let generated = quote! {
+        impl TryFrom<&[u8]> for #name {
+            type Error = sje::error::Error;
+
+            #[inline]
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                match bytes {
+                    #(#arms,)*
+                    #fallback,
+                }
+            }
+        }
+
+        impl std::str::FromStr for #name {
+            type Err = sje::error::Error;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(s.as_bytes())
+            }
+        }
+    };
+    generated.into()
+}
+
+/// Enum of the variant decoders, the variant is selected by the value of the `tag` key.
+fn handle_union(name: &syn::Ident, data_enum: DataEnum, sje_attr: SjeAttribute) -> TokenStream {
+    let struct_name = Ident::new(&format!("{}Decoder", name), name.span());
+
+    let tag = match (sje_attr.sje_type, sje_attr.tag) {
+        (SjeType::Union, Some(tag)) => tag,
+        (SjeType::Union, None) => return quote! { compile_error!("union requires the `tag` key."); }.into(),
+        _ => return quote! { compile_error!("enum can only be derived as `union`."); }.into(),
+    };
+    let variants = match union_variants(&data_enum) {
+        Ok(variants) => variants,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let mut decoder_variants = Vec::with_capacity(variants.len());
+    let mut decode_arms = Vec::with_capacity(variants.len());
+    let mut from_arms = Vec::with_capacity(variants.len());
+    let mut try_from_arms = Vec::with_capacity(variants.len());
+    for (variant, variant_type, tag_value) in &variants {
+        let decoder = format_ident!("{}Decoder", type_to_ident(variant_type).unwrap());
+        let tag_value = Literal::byte_string(tag_value.as_bytes());
+        decoder_variants.push(quote!(#variant(#decoder<'a>)));
+        decode_arms.push(quote!(#tag_value => Ok(Self::#variant(#decoder::decode(bytes)?))));
+        from_arms.push(quote!(#struct_name::#variant(decoder) => #name::#variant(decoder.into())));
+        try_from_arms
+            .push(quote!(#struct_name::#variant(decoder) => #name::#variant(<#variant_type>::try_from(decoder)?)));
+    }
+
+    let key = Literal::byte_string(format!("\"{}\":", tag).as_bytes());
+    let key_len = tag.len() + 3;
+    let tag_bytes = Literal::byte_string(tag.as_bytes());
+    let tag: &'static str = tag.leak();
+    // with whitespace around the key the fast path can't rely on the fixed key length
+    let first_key = match sje_attr.tolerant {
+        true => quote! {
+            match scanner.next_key_if(#tag_bytes) {
+                true => Some(()),
+                false => scanner.seek_key(#tag_bytes),
+            }
+        },
+        false => quote! {
+            match scanner.matches_key(#key) {
+                true => {
+                    scanner.skip(1 + #key_len);
+                    Some(())
+                }
+                false => scanner.seek_key(#tag_bytes),
+            }
+        },
+    };
+
     let generated = quote! {
-        impl From<&[u8]> for #name {
-            fn from(bytes: &[u8]) -> Self {
-                match std::str::from_utf8(bytes).unwrap() {
-                    #( stringify!(#variants) => #name::#variants, )*
-                    _ => panic!("unrecognized side"),
+        #[derive(Debug)]
+        pub enum #struct_name<'a> {
+            #(#decoder_variants,)*
+        }
+
+        impl<'a> #struct_name<'a> {
+            #[inline]
+            pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                match Self::tag(bytes)? {
+                    #(#decode_arms,)*
+                    other => Err(sje::error::Error::unknown_variant(other)),
+                }
+            }
+
+            /// Value of the discriminator, expected to be the first key with a fallback to key lookup.
+            #[inline]
+            pub fn tag(bytes: &'a [u8]) -> Result<&'a [u8], sje::error::Error> {
+                let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                let found = #first_key;
+                let (offset, len) = found
+                    .and_then(|_| scanner.next_string())
+                    .ok_or(sje::error::Error::MissingField(#tag))?;
+                Ok(unsafe { bytes.get_unchecked(offset..offset + len) })
+            }
+        }
+
+        impl From<#struct_name<'_>> for #name {
+            #[inline]
+            fn from(decoder: #struct_name<'_>) -> Self {
+                match decoder {
+                    #(#from_arms,)*
                 }
             }
         }
+
+        impl TryFrom<&#struct_name<'_>> for #name {
+            type Error = sje::error::Error;
+
+            #[inline]
+            fn try_from(decoder: &#struct_name<'_>) -> Result<Self, Self::Error> {
+                Ok(match decoder {
+                    #(#try_from_arms,)*
+                })
+            }
+        }
     };
+
     generated.into()
 }
 
+/// Union variants with the wrapped struct type and the tag value.
+fn union_variants(data_enum: &DataEnum) -> syn::Result<Vec<(&Ident, &Type, String)>> {
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let variant_type = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                _ => return Err(Error::new_spanned(variant, "union variant must wrap exactly one struct")),
+            };
+            if type_to_ident(variant_type).is_none() {
+                return Err(Error::new_spanned(variant_type, "union variant must wrap a named struct"));
+            }
+            let tag_value = sje_variant_attribute(variant)
+                .name
+                .unwrap_or_else(|| variant.ident.to_string());
+            Ok((&variant.ident, variant_type, tag_value))
+        })
+        .collect()
+}
+
 fn handle_struct(name: &syn::Ident, data_struct: DataStruct, sje_attr: SjeAttribute) -> TokenStream {
     match sje_attr.sje_type {
         SjeType::Object => handle_sje_object(name, data_struct, sje_attr),
-        SjeType::Array => unimplemented!("array not supported"),
-        SjeType::Tuple => unimplemented!("tuple not supported"),
-        SjeType::Union => unimplemented!("union not supported"),
+        SjeType::Array => handle_sje_array(name, data_struct, sje_attr),
+        SjeType::Tuple => handle_sje_object(name, data_struct, sje_attr),
+        SjeType::Union => quote! { compile_error!("union can only be derived on an enum."); }.into(),
     }
 }
 
-fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeAttribute) -> TokenStream {
+/// Top level json array, the struct wraps a single `Vec<T>` field with the elements.
+fn handle_sje_array(name: &syn::Ident, data_struct: DataStruct, sje_attr: SjeAttribute) -> TokenStream {
     let struct_name = Ident::new(&format!("{}Decoder", name), name.span());
+    let iterator_name = Ident::new(&format!("{}Iter", name), name.span());
 
-    let fields = match data_struct.fields {
-        Fields::Named(fields) => fields.named,
-        _ => return quote! { compile_error!("Decoder can only be derived for structs with named fields."); }.into(),
+    let field = match array_field(&data_struct.fields) {
+        Ok(field) => field,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let element_type = vec_element_type(&field.ty).unwrap();
+    let sje_field = sje_field_attribute(field);
+    let array_impl = array_impl(&struct_name, &iterator_name, element_type, &sje_field, sje_attr.tolerant);
+    let field_value = match &field.ident {
+        Some(field_name) => quote!(#name { #field_name: decoder.into() }),
+        None => quote!(#name(decoder.into())),
+    };
+    let elements = match sje_field.decoder {
+        true => quote! {
+            decoder
+                .try_iter()
+                .map(|item| item.and_then(|decoder| <#element_type>::try_from(&decoder)))
+                .collect::<Result<_, _>>()?
+        },
+        false => quote!(decoder.try_iter().collect::<Result<_, _>>()?),
+    };
+    let try_field_value = match &field.ident {
+        Some(field_name) => quote!(#name { #field_name: #elements }),
+        None => quote!(#name(#elements)),
     };
 
-    let field_initializations = fields.iter().map(|field| {
-        let field_name = field.ident.as_ref().unwrap();
-        let mut key_len = field_name.to_string().len();
-        let mut val_len = None;
-        let mut ty_override = None;
-        if let Some(sje_attr) = field.attrs.iter().find(|attr| attr.path().is_ident("sje")) {
-            let sje_field = sje_attr.parse_args::<SjeFieldAttribute>().expect("unable to parse");
-            if let Some(name) = sje_field.name {
-                key_len = name.len();
+    let generated = quote! {
+        #[derive(Debug)]
+        pub struct #struct_name<'a> {
+            bytes: &'a [u8],
+            remaining: usize,
+        }
+
+        impl<'a> #struct_name<'a> {
+            #[inline]
+            pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                let (offset, len, count) = scanner.next_array().ok_or_else(|| sje::error::Error::other("expected json array"))?;
+                Ok(Self { bytes: unsafe { bytes.get_unchecked(offset..offset + len) }, remaining: count })
+            }
+
+            #[inline]
+            pub const fn as_slice(&self) -> &[u8] {
+                self.bytes
             }
-            if let Some(len) = sje_field.len {
-                val_len = Some(len);
+
+            #[inline]
+            pub const fn len(&self) -> usize {
+                self.remaining
+            }
+
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.remaining == 0
             }
-            if let Some(ty) = sje_field.ty {
-                ty_override = Some(ty);
+
+            #[inline]
+            pub const fn iter(&self) -> #iterator_name<'a> {
+                #iterator_name { scanner: sje::scanner::JsonScanner::wrap(self.bytes), remaining: self.remaining }
             }
-            key_len += sje_field.offset;
         }
 
-        match resolve_type(&field.ty, ty_override) {
-            Ok(type_str) => {
-                key_len += 4;
-                match val_len {
-                    Some(known_len) => {
-                        let next = Ident::new(&format!("next_{}_with_known_len", type_str), field_name.span());
-                        let field_name_string = field_name.to_string();
-                        quote! {
-                            scanner.skip(#key_len);
-                            let (offset, len) = scanner.#next(#known_len).ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
-                            let #field_name = sje::LazyField::from_bytes(unsafe { bytes.get_unchecked(offset..offset + len) });
+        #array_impl
+
+        impl From<#struct_name<'_>> for #name {
+            #[inline]
+            fn from(decoder: #struct_name<'_>) -> Self {
+                #field_value
+            }
+        }
+
+        impl TryFrom<&#struct_name<'_>> for #name {
+            type Error = sje::error::Error;
+
+            #[inline]
+            fn try_from(decoder: &#struct_name<'_>) -> Result<Self, Self::Error> {
+                Ok(#try_field_value)
+            }
+        }
+    };
+
+    generated.into()
+}
+
+/// The only field of the `array` struct, must be `Vec<T>`.
+fn array_field(fields: &Fields) -> syn::Result<&Field> {
+    let mut iter = fields.iter();
+    match (iter.next(), iter.next()) {
+        (Some(field), None) if vec_element_type(&field.ty).is_some() => Ok(field),
+        (Some(field), None) => Err(Error::new_spanned(&field.ty, "array must be declared as Vec<T>")),
+        _ => Err(Error::new(Span::call_site(), "array struct must have exactly one Vec<T> field")),
+    }
+}
+
+/// Handles both `object` and `tuple`, the latter maps positional array elements to the named fields.
+fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, sje_attr: SjeAttribute) -> TokenStream {
+    let struct_name = Ident::new(&format!("{}Decoder", name), name.span());
+
+    let fields = match data_struct.fields {
+        Fields::Named(fields) => fields.named,
+        _ => return quote! { compile_error!("Decoder can only be derived for structs with named fields."); }.into(),
+    };
+
+    let tuple = matches!(sje_attr.sje_type, SjeType::Tuple);
+    if tuple && (sje_attr.verify_keys || sje_attr.fallback) {
+        return quote! { compile_error!("tuple has no keys to verify or fall back to."); }.into();
+    }
+    if sje_attr.lazy && sje_attr.fallback {
+        return quote! { compile_error!("lazy decoder can only scan the fields in order, remove `fallback`."); }.into();
+    }
+
+    let field_initializations = fields
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let sje_field = sje_field_attribute(field);
+            let key = sje_field.name.clone().unwrap_or_else(|| field_name.to_string());
+            let key_len = key.len() + 4 + sje_field.offset;
+
+            let field_name_string = field_name.to_string();
+            let value = match sje_field.skip {
+                true => quote! {
+                    scanner.skip_value().ok_or(sje::error::Error::MissingField(#field_name_string))?
+                },
+                false => match field_value(field, &sje_field, sje_field.len, sje_attr.tolerant) {
+                    Ok(value) => value,
+                    Err(e) => return e.to_compile_error(),
+                },
+            };
+            if tuple {
+                // skip the opening bracket or the comma that precedes the element
+                let skip_element = match sje_attr.tolerant {
+                    true => quote! {
+                        scanner.next_element().ok_or(sje::error::Error::MissingField(#field_name_string))?;
+                    },
+                    false => {
+                        let element_offset = 1 + sje_field.offset;
+                        quote!(scanner.skip(#element_offset);)
+                    }
+                };
+                return match option_inner_type(&field.ty) {
+                    _ if sje_field.skip => quote! {
+                        #skip_element
+                        #value;
+                    },
+                    Some(_) => quote! {
+                        #skip_element
+                        let #field_name = if scanner.next_null().is_some() { None } else { Some(#value) };
+                    },
+                    None => quote! {
+                        #skip_element
+                        let #field_name = #value;
+                    },
+                };
+            }
+            let key_bytes = Literal::byte_string(key.as_bytes());
+            // with fallback an absent optional key may appear later, the caller checks for that after the last field
+            let mark_absent = match sje_attr.fallback {
+                true => quote!(optional_absent = true;),
+                false => quote!(),
+            };
+            let skip_key = match (sje_attr.tolerant, sje_attr.verify_keys || sje_attr.fallback) {
+                (true, true) => quote! {
+                    let (offset, len) = scanner.next_key().ok_or(sje::error::Error::MissingField(#field_name_string))?;
+                    let found = unsafe { bytes.get_unchecked(offset..offset + len) };
+                    if found != #key_bytes {
+                        return Err(sje::error::Error::unexpected_key(#key, Some(found)));
+                    }
+                },
+                (true, false) => quote! {
+                    scanner.next_key().ok_or(sje::error::Error::MissingField(#field_name_string))?;
+                },
+                (false, true) => {
+                    let quoted_key = Literal::byte_string(format!("\"{}\":", key).as_bytes());
+                    quote! {
+                        if !scanner.matches_key(#quoted_key) {
+                            return Err(sje::error::Error::unexpected_key(#key, scanner.peek_key()));
                         }
+                        scanner.skip(#key_len);
                     }
-                    None => {
-                        let next = Ident::new(&format!("next_{}", type_str), field_name.span());
-                        let field_name_string = field_name.to_string();
-                        if type_str == "array" {
-                            quote! {
-                                scanner.skip(#key_len);
-                                let (offset, len, count) = scanner.#next().ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
-                                let #field_name = (unsafe { bytes.get_unchecked(offset..offset + len) }, count);
-                            }
+                }
+                (false, false) => quote! {
+                    scanner.skip(#key_len);
+                },
+            };
+            match (option_inner_type(&field.ty), sje_field.nullable) {
+                _ if sje_field.skip => quote! {
+                    #skip_key
+                    #value;
+                },
+                // key is always present but the value can be null
+                (Some(_), true) => quote! {
+                    #skip_key
+                    let #field_name = if scanner.next_null().is_some() { None } else { Some(#value) };
+                },
+                // optional key is only consumed when present at the expected position
+                (Some(_), false) if sje_attr.tolerant => quote! {
+                    let #field_name = if scanner.next_key_if(#key_bytes) {
+                        if scanner.next_null().is_some() { None } else { Some(#value) }
+                    } else {
+                        #mark_absent
+                        None
+                    };
+                },
+                (Some(_), false) => {
+                    let quoted_key = Literal::byte_string(format!("\"{}\":", key).as_bytes());
+                    quote! {
+                        let #field_name = if scanner.matches_key(#quoted_key) {
+                            scanner.skip(#key_len);
+                            if scanner.next_null().is_some() { None } else { Some(#value) }
                         } else {
-                            quote! {
-                                scanner.skip(#key_len);
-                                let (offset, len) = scanner.#next().ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
-                                let #field_name = sje::LazyField::from_bytes(unsafe { bytes.get_unchecked(offset..offset + len) });
-                            }
-                        }
+                            #mark_absent
+                            None
+                        };
                     }
                 }
+                (None, true) => syn::Error::new_spanned(&field.ty, "nullable fields must be declared as Option<T>")
+                    .to_compile_error(),
+                (None, false) => quote! {
+                    #skip_key
+                    let #field_name = #value;
+                },
             }
-            Err(e) => e.to_compile_error(),
-        }
-    });
+        })
+        .collect::<Vec<_>>();
+
+    // keys that may be absent, with fallback they need to be checked for having moved further down the object
+    let optional_keys = fields
+        .iter()
+        .map(|field| {
+            let sje_field = sje_field_attribute(field);
+            option_inner_type(&field.ty).is_some() && !sje_field.nullable && !sje_field.skip
+        })
+        .collect::<Vec<_>>();
+
+    // skipped fields are not stored in the decoder
+    let all_fields = &fields;
+    let fields = all_fields
+        .iter()
+        .filter(|field| !sje_field_attribute(field).skip)
+        .collect::<Vec<_>>();
+
+    let field_assignments = fields
+        .iter()
+        .map(|field| {
+            let field_name = &field.ident;
+            quote! {
+                #field_name,
+            }
+        })
+        .collect::<Vec<_>>();
 
-    let field_assignments = fields.iter().map(|field| {
+    // nested decoders are moved out of the decoder so they need to come last
+    let mut from_fields = all_fields.iter().collect::<Vec<_>>();
+    from_fields.sort_by_key(|field| nested_type(field).is_some());
+    let from_field_assignments = from_fields.iter().map(|field| {
         let field_name = &field.ident;
-        quote! {
-            #field_name,
+        match nested_type(field) {
+            _ if sje_field_attribute(field).skip => quote! {
+                #field_name: Default::default(),
+            },
+            Some(_) => quote! {
+                #field_name: decoder.#field_name.1.into(),
+            },
+            None => quote! {
+                #field_name: decoder.#field_name().into(),
+            },
         }
     });
 
-    let from_field_assignments = fields.iter().map(|field| {
-        let field_name = &field.ident;
-        quote! {
-            #field_name: decoder.#field_name().into(),
+    let try_from_field_assignments = all_fields.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let sje_field = sje_field_attribute(field);
+        let try_field_name = format_ident!("try_{}", field_name);
+        match (nested_type(field), vec_element_type(&field.ty)) {
+            _ if sje_field.skip => quote! {
+                #field_name: Default::default(),
+            },
+            (Some(nested_type), _) => quote! {
+                #field_name: <#nested_type>::try_from(decoder.#field_name())?,
+            },
+            (_, Some(element_type)) if sje_field.decoder => quote! {
+                #field_name: decoder
+                    .#field_name()
+                    .try_iter()
+                    .map(|item| item.and_then(|decoder| <#element_type>::try_from(&decoder)))
+                    .collect::<Result<_, _>>()?,
+            },
+            (_, Some(_)) => quote! {
+                #field_name: decoder.#field_name().try_iter().collect::<Result<_, _>>()?,
+            },
+            _ => quote! {
+                #field_name: decoder.#try_field_name()?,
+            },
         }
     });
 
+    // by reference, as `TryFrom` by value would conflict with the blanket impl over `From`
     let from_impl = quote! {
         impl From<#struct_name<'_>> for #name {
             fn from(decoder: #struct_name<'_>) -> Self {
@@ -252,55 +807,307 @@ fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeA
                 }
             }
         }
-    };
 
-    let decode_impl = quote! {
-        impl <'a> #struct_name<'a> {
-            #[inline]
-            pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
-                let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
-                #(#field_initializations)*
+        impl TryFrom<&#struct_name<'_>> for #name {
+            type Error = sje::error::Error;
+
+            fn try_from(decoder: &#struct_name<'_>) -> Result<Self, Self::Error> {
                 Ok(Self {
-                    #(#field_assignments)*
+                    #(#try_from_field_assignments)*
                 })
             }
         }
     };
 
-    let accessor_methods = fields.iter().map(|field| {
-        let field_name = &field.ident;
-        let as_slice = Ident::new(&format!("{}_as_slice", field_name.as_ref().unwrap()), field_name.span());
-        let as_str = Ident::new(&format!("{}_as_str", field_name.as_ref().unwrap()), field_name.span());
+    let decode_impl = match sje_attr.fallback {
+        true => {
+            let field_slots = fields.iter().map(|field| {
+                let field_name = &field.ident;
+                quote! {
+                    let mut #field_name = None;
+                }
+            });
 
-        let mut generated = quote! {};
+            let field_matches = fields.iter().map(|field| {
+                let field_name = field.ident.as_ref().unwrap();
+                let sje_field = sje_field_attribute(field);
+                let key = sje_field.name.clone().unwrap_or_else(|| field_name.to_string());
+                let key = Literal::byte_string(key.as_bytes());
+                // errors are already reported by the positional path
+                let value = field_value(field, &sje_field, None, sje_attr.tolerant)
+                    .unwrap_or_else(|_| quote! { unreachable!() });
+                match option_inner_type(&field.ty) {
+                    Some(_) => quote! {
+                        #key => #field_name = if scanner.next_null().is_some() { None } else { Some(#value) },
+                    },
+                    None => quote! {
+                        #key => #field_name = Some(#value),
+                    },
+                }
+            });
 
-        let field_type = &field.ty;
-        if let syn::Type::Path(path) = field_type {
-            if path.path.segments.last().map(|seg| seg.ident == "Vec").unwrap_or(false) {
-                let array_count = Ident::new(&format!("{}_count", field_name.as_ref().unwrap()), field_name.span());
-                generated.extend(quote! {
-                    #[inline]
-                    pub const fn #as_slice(&self) -> &[u8] {
-                        self.#field_name.0
+            let field_checks = fields
+                .iter()
+                .filter(|field| option_inner_type(&field.ty).is_none())
+                .map(|field| {
+                    let field_name = field.ident.as_ref().unwrap();
+                    let field_name_string = field_name.to_string();
+                    quote! {
+                        let #field_name = #field_name.ok_or(sje::error::Error::MissingField(#field_name_string))?;
                     }
+                });
+
+            let (optional_absent, check_absent) = match optional_keys.contains(&true) {
+                true => (
+                    quote!(let mut optional_absent = false;),
+                    quote! {
+                        if optional_absent && !scanner.is_object_end() {
+                            return Err(sje::error::Error::other("optional key out of order"));
+                        }
+                    },
+                ),
+                false => (quote!(), quote!()),
+            };
+
+            quote! {
+                impl <'a> #struct_name<'a> {
+                    /// Decode using the positional fast path, falling back to [`Self::decode_by_key`] on mismatch.
                     #[inline]
-                    pub const fn #as_str(&self) -> &str {
-                        unsafe { std::str::from_utf8_unchecked(self.#as_slice()) }
+                    pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                        match Self::decode_in_order(bytes) {
+                            Ok(decoder) => Ok(decoder),
+                            Err(_) => Self::decode_by_key(bytes),
+                        }
                     }
+
                     #[inline]
-                    pub const fn #array_count(&self) -> usize {
-                        self.#field_name.1
+                    fn decode_in_order(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                        let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                        #optional_absent
+                        #(#field_initializations)*
+                        #check_absent
+                        Ok(Self {
+                            #(#field_assignments)*
+                        })
                     }
-                })
-            } else {
-                let as_lazy_field =
-                    Ident::new(&format!("{}_as_lazy_field", field_name.as_ref().unwrap()), field_name.span());
-                generated.extend(quote! {
-                    #[inline]
-                    pub const fn #as_slice(&self) -> &[u8] {
-                        self.#field_name.as_slice()
+
+                    /// Decode by looking up each key, regardless of the order in which they appear.
+                    #[cold]
+                    pub fn decode_by_key(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                        let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                        #(#field_slots)*
+                        while let Some((offset, len)) = scanner.next_key() {
+                            match unsafe { bytes.get_unchecked(offset..offset + len) } {
+                                #(#field_matches)*
+                                _ => {
+                                    scanner.skip_value().ok_or_else(|| sje::error::Error::other("unable to skip value"))?;
+                                }
+                            }
+                        }
+                        #(#field_checks)*
+                        Ok(Self {
+                            #(#field_assignments)*
+                        })
                     }
-                    #[inline]
+                }
+            }
+        }
+        false => quote! {
+            impl <'a> #struct_name<'a> {
+                #[inline]
+                pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                    let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                    #(#field_initializations)*
+                    Ok(Self {
+                        #(#field_assignments)*
+                    })
+                }
+            }
+        },
+    };
+
+    let struct_impl = decoder_struct(&struct_name, &fields, true, sje_attr.tolerant);
+
+    // decoders of the leading fields that stop scanning after the marked field
+    let prefix_decoders = all_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| sje_field_attribute(field).until)
+        .map(|(index, field)| {
+            let field_name = field.ident.as_ref().unwrap();
+            let prefix_name = format_ident!("{}Until{}Decoder", name, field_name.to_string().to_upper_camel_case());
+            let decode_until = format_ident!("decode_until_{}", field_name);
+            let prefix_fields = all_fields
+                .iter()
+                .take(index + 1)
+                .filter(|field| !sje_field_attribute(field).skip)
+                .collect::<Vec<_>>();
+            let prefix_initializations = &field_initializations[..=index];
+            let prefix_field_names = prefix_fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+            let prefix_struct = decoder_struct(&prefix_name, &prefix_fields, false, sje_attr.tolerant);
+
+            // an absent optional key could still follow the prefix, so only the full lookup can tell
+            let (optional_absent, check_absent) = match sje_attr.fallback && optional_keys[..=index].contains(&true) {
+                true => (
+                    quote!(let mut optional_absent = false;),
+                    quote! {
+                        if optional_absent {
+                            return Err(sje::error::Error::other("optional key out of order"));
+                        }
+                    },
+                ),
+                false => (quote!(), quote!()),
+            };
+            let decode_in_order = quote! {
+                let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                #optional_absent
+                #(#prefix_initializations)*
+                #check_absent
+                Ok(Self {
+                    #(#prefix_field_names,)*
+                })
+            };
+            let decode = match sje_attr.fallback {
+                true => quote! {
+                    match Self::decode_in_order(bytes) {
+                        Ok(decoder) => Ok(decoder),
+                        Err(_) => {
+                            let decoder = #struct_name::decode_by_key(bytes)?;
+                            Ok(Self {
+                                #(#prefix_field_names: decoder.#prefix_field_names,)*
+                            })
+                        }
+                    }
+                },
+                false => quote!(Self::decode_in_order(bytes)),
+            };
+            let doc = format!("Decode the fields up to and including `{}` without scanning the rest.", field_name);
+
+            quote! {
+                #prefix_struct
+
+                impl<'a> #prefix_name<'a> {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn decode(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                        #decode
+                    }
+
+                    #[inline]
+                    fn decode_in_order(bytes: &'a [u8]) -> Result<Self, sje::error::Error> {
+                        #decode_in_order
+                    }
+                }
+
+                impl<'a> #struct_name<'a> {
+                    #[inline]
+                    pub fn #decode_until(bytes: &'a [u8]) -> Result<#prefix_name<'a>, sje::error::Error> {
+                        #prefix_name::decode(bytes)
+                    }
+                }
+            }
+        });
+
+    let lazy_decoder = match sje_attr.lazy {
+        true => lazy_decoder(name, &all_fields.iter().collect::<Vec<_>>(), &field_initializations),
+        false => quote! {},
+    };
+
+    let generated = quote! {
+        #struct_impl
+
+        #from_impl
+
+        #decode_impl
+
+        #(#prefix_decoders)*
+
+        #lazy_decoder
+    };
+
+    generated.into()
+}
+
+/// Decoder struct with the accessors of the decoded fields.
+fn decoder_struct(
+    struct_name: &Ident,
+    fields: &[&Field],
+    array_types: bool,
+    tolerant: bool,
+) -> proc_macro2::TokenStream {
+    let accessor_methods = fields.iter().map(|field| {
+        let field_name = &field.ident;
+        let as_slice = Ident::new(&format!("{}_as_slice", field_name.as_ref().unwrap()), field_name.span());
+        let as_str = Ident::new(&format!("{}_as_str", field_name.as_ref().unwrap()), field_name.span());
+
+        let mut generated = quote! {};
+
+        let field_type = &field.ty;
+        if let syn::Type::Path(path) = field_type {
+            if path.path.segments.last().map(|seg| seg.ident == "Vec").unwrap_or(false) {
+                let array_count = Ident::new(&format!("{}_count", field_name.as_ref().unwrap()), field_name.span());
+                generated.extend(quote! {
+                    #[inline]
+                    pub const fn #as_slice(&self) -> &[u8] {
+                        self.#field_name.0
+                    }
+                    #[inline]
+                    pub const fn #as_str(&self) -> &str {
+                        unsafe { std::str::from_utf8_unchecked(self.#as_slice()) }
+                    }
+                    #[inline]
+                    pub const fn #array_count(&self) -> usize {
+                        self.#field_name.1
+                    }
+                })
+            } else if let Some(nested_type) = nested_type(field) {
+                let nested_decoder = format_ident!("{}Decoder", type_to_ident(nested_type).unwrap());
+                generated.extend(quote! {
+                    #[inline]
+                    pub const fn #as_slice(&self) -> &[u8] {
+                        self.#field_name.0
+                    }
+                    #[inline]
+                    pub const fn #as_str(&self) -> &str {
+                        unsafe { std::str::from_utf8_unchecked(self.#as_slice()) }
+                    }
+                    #[inline]
+                    pub const fn #field_name(&self) -> &#nested_decoder<'a> {
+                        &self.#field_name.1
+                    }
+                })
+            } else if let Some(inner_type) = option_inner_type(field_type) {
+                let as_lazy_field =
+                    Ident::new(&format!("{}_as_lazy_field", field_name.as_ref().unwrap()), field_name.span());
+                generated.extend(quote! {
+                    #[inline]
+                    pub const fn #as_slice(&self) -> Option<&[u8]> {
+                        match &self.#field_name {
+                            Some(field) => Some(field.as_slice()),
+                            None => None,
+                        }
+                    }
+                    #[inline]
+                    pub const fn #as_str(&self) -> Option<&str> {
+                        match &self.#field_name {
+                            Some(field) => Some(field.as_str()),
+                            None => None,
+                        }
+                    }
+                    #[inline]
+                    pub const fn #as_lazy_field(&self) -> Option<&sje::LazyField<'a, #inner_type>> {
+                        self.#field_name.as_ref()
+                    }
+                })
+            } else {
+                let as_lazy_field =
+                    Ident::new(&format!("{}_as_lazy_field", field_name.as_ref().unwrap()), field_name.span());
+                generated.extend(quote! {
+                    #[inline]
+                    pub const fn #as_slice(&self) -> &[u8] {
+                        self.#field_name.as_slice()
+                    }
+                    #[inline]
                     pub const fn #as_str(&self) -> &str {
                         self.#field_name.as_str()
                     }
@@ -312,6 +1119,26 @@ fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeA
             }
         }
 
+        // json strings can also be decoded with any escape sequences resolved
+        let sje_field = sje_field_attribute(field);
+        if nested_type(field).is_none() && matches!(resolve_type(field_type, sje_field.ty), Ok("string")) {
+            let unescaped = format_ident!("{}_unescaped", field_name.as_ref().unwrap());
+            match option_inner_type(field_type) {
+                Some(_) => generated.extend(quote! {
+                    #[inline]
+                    pub fn #unescaped(&self) -> Result<Option<std::borrow::Cow<'a, str>>, sje::error::Error> {
+                        self.#field_name.as_ref().map(|field| sje::unescape(field.as_slice())).transpose()
+                    }
+                }),
+                None => generated.extend(quote! {
+                    #[inline]
+                    pub fn #unescaped(&self) -> Result<std::borrow::Cow<'a, str>, sje::error::Error> {
+                        sje::unescape(self.#field_name.as_slice())
+                    }
+                }),
+            }
+        }
+
         if let Some(sje_attr) = field.attrs.iter().find(|attr| attr.path().is_ident("sje")) {
             let sje_field = sje_attr.parse_args::<SjeFieldAttribute>().expect("unable to parse");
             if let Some(also_as) = sje_field.also_as {
@@ -321,13 +1148,36 @@ fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeA
                     &format!("{}_as_{}", field_name.as_ref().unwrap(), type_name.to_snake_case()),
                     field_name.span(),
                 );
-                generated.extend(quote! {
+                let try_also_as = format_ident!("try_{}", also_as);
+                match option_inner_type(&field.ty) {
+                    Some(_) => generated.extend(quote! {
 
-                    #[inline]
-                    pub fn #also_as(&self) -> #type_name_ident {
-                        self.#as_str().parse().unwrap()
-                    }
-                });
+                        #[inline]
+                        pub fn #also_as(&self) -> Option<#type_name_ident> {
+                            self.#as_str().map(|s| s.parse().unwrap())
+                        }
+
+                        #[inline]
+                        pub fn #try_also_as(&self) -> Result<Option<#type_name_ident>, sje::error::Error> {
+                            self.#as_str()
+                                .map(|s| s.parse().map_err(|_| sje::error::Error::Parse(s.to_owned())))
+                                .transpose()
+                        }
+                    }),
+                    None => generated.extend(quote! {
+
+                        #[inline]
+                        pub fn #also_as(&self) -> #type_name_ident {
+                            self.#as_str().parse().unwrap()
+                        }
+
+                        #[inline]
+                        pub fn #try_also_as(&self) -> Result<#type_name_ident, sje::error::Error> {
+                            let s = self.#as_str();
+                            s.parse().map_err(|_| sje::error::Error::Parse(s.to_owned()))
+                        }
+                    }),
+                }
             }
         }
 
@@ -336,151 +1186,82 @@ fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeA
 
     let new_fields = fields.iter().map(|field| {
         let field_name = &field.ident;
-        let field_type = &field.ty;
-        if let syn::Type::Path(path) = field_type {
-            if path.path.segments.last().map(|seg| seg.ident == "Vec").unwrap_or(false) {
-                quote! {
-                    #field_name: (&'a [u8], usize),
-                }
-            } else {
-                quote! {
-                    #field_name: sje::LazyField<'a, #field_type>,
-                }
-            }
-        } else {
-            quote! {}
+        match decoder_field_type(field) {
+            Some(field_type) => quote! {
+                #field_name: #field_type,
+            },
+            None => quote! {},
         }
     });
 
     let iterators = fields.iter().map(|field| {
-        let mut decoder = false;
-        if let Some(sje_attr) = field.attrs.iter().find(|attr| attr.path().is_ident("sje")) {
-            let sje_field = sje_attr.parse_args::<SjeFieldAttribute>().expect("unable to parse");
-            decoder = sje_field.decoder
-        }
-
+        let sje_field = sje_field_attribute(field);
         let field_name = &field.ident;
         let field_type = &field.ty;
 
         if let syn::Type::Path(path) = field_type {
             if path.path.segments.last().map(|seg| seg.ident == "Vec").unwrap_or(false) {
-                if let Some(segment) = path.path.segments.last() {
-                    if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                        if let Some(syn::GenericArgument::Type(arg_type)) = args.args.first() {
-                            let array_struct_name =
-                                format_ident!("{}", field_name.as_ref().unwrap().to_string().to_upper_camel_case());
-                            let array_fn_name = format_ident!("{}", field_name.as_ref().unwrap().to_string());
-                            let iterator_name =
-                                format_ident!("{}Iter", field_name.as_ref().unwrap().to_string().to_upper_camel_case());
-                            let next_impl = iterator_next_impl(arg_type, decoder);
-
-                            let mut code = quote! {
-                                #[derive(Debug)]
-                                pub struct #array_struct_name<'a> {
-                                    bytes: &'a [u8],
-                                    remaining: usize,
-                                }
+                if let Some(arg_type) = vec_element_type(field_type) {
+                    let array_struct_name =
+                        format_ident!("{}", field_name.as_ref().unwrap().to_string().to_upper_camel_case());
+                    let array_fn_name = format_ident!("{}", field_name.as_ref().unwrap().to_string());
+                    let iterator_name =
+                        format_ident!("{}Iter", field_name.as_ref().unwrap().to_string().to_upper_camel_case());
+                    let array_impl = array_impl(&array_struct_name, &iterator_name, arg_type, &sje_field, tolerant);
+
+                    // array types are shared with the prefix decoders
+                    let array_types = match array_types {
+                        true => quote! {
+                            #[derive(Debug)]
+                            pub struct #array_struct_name<'a> {
+                                bytes: &'a [u8],
+                                remaining: usize,
+                            }
 
-                                impl #struct_name<'_> {
-                                    #[inline]
-                                    pub const fn #array_fn_name(&self) -> #array_struct_name {
-                                        #array_struct_name { bytes: self.#array_fn_name.0, remaining: self.#array_fn_name.1 }
-                                    }
-                                }
-                                pub struct #iterator_name<'a> {
-                                    scanner: sje::scanner::JsonScanner<'a>,
-                                    remaining: usize,
-                                }
-                                impl ExactSizeIterator for #iterator_name<'_> {
+                            #array_impl
+                        },
+                        false => quote! {},
+                    };
 
-                                    #[inline]
-                                    fn len(&self) -> usize {
-                                        self.remaining
-                                    }
-                                }
-                            };
-
-                            if decoder {
-                                let arg_type_decoder = format_ident!("{}Decoder", type_to_ident(arg_type).unwrap());
-                                code.extend(quote! {
-                                    impl <'a> From<#array_struct_name<'a>> for Vec<#arg_type_decoder<'a>> {
-                                        fn from(value: #array_struct_name<'a>) -> Self {
-                                            value.into_iter().collect()
-                                        }
-                                    }
-
-                                    impl<'a> IntoIterator for #array_struct_name<'a> {
-                                        type Item = #arg_type_decoder<'a>;
-                                        type IntoIter = #iterator_name<'a>;
-                                        fn into_iter(self) -> Self::IntoIter {
-                                            #iterator_name {
-                                                scanner: sje::scanner::JsonScanner::wrap(self.bytes),
-                                                remaining: self.remaining
-                                            }
-                                        }
-                                    }
-                                    impl <'a> Iterator for #iterator_name<'a> {
-                                        type Item = #arg_type_decoder<'a>;
-                                        #[inline]
-                                        fn next(&mut self) -> Option<Self::Item> {
-                                            #next_impl
-                                        }
-                                        #[inline]
-                                        fn size_hint(&self) -> (usize, Option<usize>) {
-                                            (self.remaining, Some(self.remaining))
-                                        }
-                                    }
-                                    impl From<#array_struct_name<'_>> for Vec<#arg_type> {
-                                        fn from(value: #array_struct_name<'_>) -> Self {
-                                            value.into_iter().map(|decoder| decoder.into()).collect()
-                                        }
-                                    }
-                                });
-                            } else {
-                                code.extend(quote! {
-                                    impl From<#array_struct_name<'_>> for Vec<#arg_type> {
-                                        fn from(value: #array_struct_name) -> Self {
-                                            value.into_iter().collect()
-                                        }
-                                    }
-
-                                    impl<'a> IntoIterator for #array_struct_name<'a> {
-                                        type Item = #arg_type;
-                                        type IntoIter = #iterator_name<'a>;
-
-                                        fn into_iter(self) -> Self::IntoIter {
-                                            #iterator_name {
-                                                scanner: sje::scanner::JsonScanner::wrap(self.bytes),
-                                                remaining: self.remaining
-                                            }
-                                        }
-                                    }
-
-                                    impl Iterator for #iterator_name<'_> {
-                                        type Item = #arg_type;
-
-                                        #[inline]
-                                        fn next(&mut self) -> Option<Self::Item> {
-                                            #next_impl
-                                        }
-                                        #[inline]
-                                        fn size_hint(&self) -> (usize, Option<usize>) {
-                                            (self.remaining, Some(self.remaining))
-                                        }
-                                    }
-                                });
+                    return quote! {
+                        #array_types
+
+                        impl #struct_name<'_> {
+                            #[inline]
+                            pub const fn #array_fn_name(&self) -> #array_struct_name {
+                                #array_struct_name { bytes: self.#array_fn_name.0, remaining: self.#array_fn_name.1 }
                             }
-                            return code;
                         }
-                    }
+                    };
                 }
+            } else if nested_type(field).is_some() {
+                return quote! {};
+            } else if option_inner_type(field_type).is_some() {
+                let try_field_name = format_ident!("try_{}", field_name.as_ref().unwrap());
+                return quote! {
+                    impl #struct_name<'_> {
+                        #[inline]
+                        pub fn #field_name(&self) -> #field_type {
+                            self.#field_name.as_ref().map(|field| field.get().unwrap())
+                        }
+                        #[inline]
+                        pub fn #try_field_name(&self) -> Result<#field_type, sje::error::Error> {
+                            self.#field_name.as_ref().map(|field| field.get()).transpose()
+                        }
+                    }
+                };
             } else {
+                let try_field_name = format_ident!("try_{}", field_name.as_ref().unwrap());
                 return quote! {
                     impl #struct_name<'_> {
                         #[inline]
                         pub fn #field_name(&self) -> #field_type {
                             self.#field_name.get().unwrap()
                         }
+                        #[inline]
+                        pub fn #try_field_name(&self) -> Result<#field_type, sje::error::Error> {
+                            self.#field_name.get()
+                        }
                     }
                 };
             }
@@ -488,27 +1269,484 @@ fn handle_sje_object(name: &syn::Ident, data_struct: DataStruct, _sje_attr: SjeA
         quote! {}
     });
 
-    let generated = quote! {
+    quote! {
         #[derive(Debug)]
         pub struct #struct_name<'a> {
             #(#new_fields)*
         }
 
-        #from_impl
-
-        #decode_impl
-
         impl <'a> #struct_name<'a> {
             #(#accessor_methods)*
         }
 
         #(#iterators)*
+    }
+}
+
+/// Type in which the decoder stores the located field value.
+fn decoder_field_type(field: &Field) -> Option<proc_macro2::TokenStream> {
+    let field_type = &field.ty;
+    if let syn::Type::Path(path) = field_type {
+        if path.path.segments.last().map(|seg| seg.ident == "Vec").unwrap_or(false) {
+            Some(quote!((&'a [u8], usize)))
+        } else if let Some(nested_type) = nested_type(field) {
+            let nested_decoder = format_ident!("{}Decoder", type_to_ident(nested_type).unwrap());
+            Some(quote!((&'a [u8], #nested_decoder<'a>)))
+        } else if let Some(inner_type) = option_inner_type(field_type) {
+            Some(quote!(Option<sje::LazyField<'a, #inner_type>>))
+        } else {
+            Some(quote!(sje::LazyField<'a, #field_type>))
+        }
+    } else {
+        None
+    }
+}
+
+/// Decoder that keeps only the buffer and scans up to the requested field on first access. Fields that were
+/// passed on the way are cached, so each field is located at most once.
+fn lazy_decoder(
+    name: &Ident,
+    all_fields: &[&Field],
+    field_initializations: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let struct_name = format_ident!("{}LazyDecoder", name);
+    let fields = all_fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !sje_field_attribute(field).skip)
+        .collect::<Vec<_>>();
+
+    let slots = fields.iter().map(|(_, field)| {
+        let field_name = &field.ident;
+        let field_type = decoder_field_type(field);
+        quote! {
+            #field_name: std::cell::OnceCell<#field_type>,
+        }
+    });
+    let slot_initializations = fields.iter().map(|(_, field)| {
+        let field_name = &field.ident;
+        quote! {
+            #field_name: std::cell::OnceCell::new(),
+        }
+    });
+
+    // skipped fields still have to be scanned to keep the position
+    let scan_arms = all_fields
+        .iter()
+        .zip(field_initializations)
+        .enumerate()
+        .map(|(index, (field, init))| {
+            let field_name = &field.ident;
+            let store = match sje_field_attribute(field).skip {
+                true => quote! {},
+                false => quote! {
+                    let _ = self.#field_name.set(#field_name);
+                },
+            };
+            quote! {
+                #index => {
+                    #init
+                    #store
+                }
+            }
+        });
+
+    let accessors = fields.iter().map(|(index, field)| {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = decoder_field_type(field);
+        let located = format_ident!("located_{}", field_name);
+        let as_slice = format_ident!("{}_as_slice", field_name);
+        let as_str = format_ident!("{}_as_str", field_name);
+        let sje_field = sje_field_attribute(field);
+
+        let mut generated = quote! {
+            #[inline]
+            fn #located(&self) -> Result<&#field_type, sje::error::Error> {
+                if let Some(field) = self.#field_name.get() {
+                    return Ok(field);
+                }
+                self.scan_to(#index)?;
+                Ok(self.#field_name.get().unwrap())
+            }
+        };
+
+        if vec_element_type(&field.ty).is_some() {
+            let array_struct_name = format_ident!("{}", field_name.to_string().to_upper_camel_case());
+            let array_count = format_ident!("{}_count", field_name);
+            generated.extend(quote! {
+                #[inline]
+                pub fn #as_slice(&self) -> Result<&'a [u8], sje::error::Error> {
+                    Ok(self.#located()?.0)
+                }
+                #[inline]
+                pub fn #as_str(&self) -> Result<&'a str, sje::error::Error> {
+                    Ok(unsafe { std::str::from_utf8_unchecked(self.#as_slice()?) })
+                }
+                #[inline]
+                pub fn #array_count(&self) -> Result<usize, sje::error::Error> {
+                    Ok(self.#located()?.1)
+                }
+                #[inline]
+                pub fn #field_name(&self) -> Result<#array_struct_name<'a>, sje::error::Error> {
+                    let (bytes, remaining) = *self.#located()?;
+                    Ok(#array_struct_name { bytes, remaining })
+                }
+            });
+        } else if let Some(nested_type) = nested_type(field) {
+            let nested_decoder = format_ident!("{}Decoder", type_to_ident(nested_type).unwrap());
+            generated.extend(quote! {
+                #[inline]
+                pub fn #as_slice(&self) -> Result<&'a [u8], sje::error::Error> {
+                    Ok(self.#located()?.0)
+                }
+                #[inline]
+                pub fn #as_str(&self) -> Result<&'a str, sje::error::Error> {
+                    Ok(unsafe { std::str::from_utf8_unchecked(self.#as_slice()?) })
+                }
+                #[inline]
+                pub fn #field_name(&self) -> Result<&#nested_decoder<'a>, sje::error::Error> {
+                    Ok(&self.#located()?.1)
+                }
+            });
+        } else if let Some(inner_type) = option_inner_type(&field.ty) {
+            generated.extend(quote! {
+                #[inline]
+                pub fn #as_slice(&self) -> Result<Option<&'a [u8]>, sje::error::Error> {
+                    Ok(self.#located()?.as_ref().map(|field| field.as_slice()))
+                }
+                #[inline]
+                pub fn #as_str(&self) -> Result<Option<&'a str>, sje::error::Error> {
+                    Ok(self.#located()?.as_ref().map(|field| field.as_str()))
+                }
+                #[inline]
+                pub fn #field_name(&self) -> Result<Option<#inner_type>, sje::error::Error> {
+                    self.#located()?.as_ref().map(|field| field.get()).transpose()
+                }
+            });
+            if matches!(resolve_type(&field.ty, sje_field.ty), Ok("string")) {
+                let unescaped = format_ident!("{}_unescaped", field_name);
+                generated.extend(quote! {
+                    #[inline]
+                    pub fn #unescaped(&self) -> Result<Option<std::borrow::Cow<'a, str>>, sje::error::Error> {
+                        self.#as_slice()?.map(sje::unescape).transpose()
+                    }
+                });
+            }
+        } else {
+            let ty = &field.ty;
+            generated.extend(quote! {
+                #[inline]
+                pub fn #as_slice(&self) -> Result<&'a [u8], sje::error::Error> {
+                    Ok(self.#located()?.as_slice())
+                }
+                #[inline]
+                pub fn #as_str(&self) -> Result<&'a str, sje::error::Error> {
+                    Ok(self.#located()?.as_str())
+                }
+                #[inline]
+                pub fn #field_name(&self) -> Result<#ty, sje::error::Error> {
+                    self.#located()?.get()
+                }
+            });
+            if matches!(resolve_type(&field.ty, sje_field.ty), Ok("string")) {
+                let unescaped = format_ident!("{}_unescaped", field_name);
+                generated.extend(quote! {
+                    #[inline]
+                    pub fn #unescaped(&self) -> Result<std::borrow::Cow<'a, str>, sje::error::Error> {
+                        sje::unescape(self.#as_slice()?)
+                    }
+                });
+            }
+        }
+        generated
+    });
+
+    quote! {
+        #[derive(Debug)]
+        pub struct #struct_name<'a> {
+            bytes: &'a [u8],
+            /// position after the last scanned field
+            cursor: std::cell::Cell<usize>,
+            /// number of fields scanned so far
+            scanned: std::cell::Cell<usize>,
+            #(#slots)*
+        }
+
+        impl<'a> #struct_name<'a> {
+            /// Wrap the message without scanning any of the fields.
+            #[inline]
+            pub const fn wrap(bytes: &'a [u8]) -> Self {
+                Self {
+                    bytes,
+                    cursor: std::cell::Cell::new(0),
+                    scanned: std::cell::Cell::new(0),
+                    #(#slot_initializations)*
+                }
+            }
+
+            #[inline]
+            pub const fn as_slice(&self) -> &'a [u8] {
+                self.bytes
+            }
+
+            #[inline]
+            fn scan_to(&self, index: usize) -> Result<(), sje::error::Error> {
+                let bytes = self.bytes;
+                let mut scanner = sje::scanner::JsonScanner::wrap(bytes);
+                scanner.skip(self.cursor.get());
+                while self.scanned.get() <= index {
+                    match self.scanned.get() {
+                        #(#scan_arms)*
+                        _ => unreachable!(),
+                    }
+                    self.cursor.set(scanner.position());
+                    self.scanned.set(self.scanned.get() + 1);
+                }
+                Ok(())
+            }
+
+            #(#accessors)*
+        }
+    }
+}
+
+/// Iterator over the array elements, `array_struct_name` must have `bytes` and `remaining` fields.
+fn array_impl(
+    array_struct_name: &Ident,
+    iterator_name: &Ident,
+    arg_type: &Type,
+    sje_field: &SjeFieldAttribute,
+    tolerant: bool,
+) -> proc_macro2::TokenStream {
+    let decoder = sje_field.decoder;
+    let next_impl = iterator_next_impl(arg_type, sje_field, tolerant, false);
+
+    let mut code = quote! {
+        pub struct #iterator_name<'a> {
+            scanner: sje::scanner::JsonScanner<'a>,
+            remaining: usize,
+        }
+        impl ExactSizeIterator for #iterator_name<'_> {
+
+            #[inline]
+            fn len(&self) -> usize {
+                self.remaining
+            }
+        }
     };
 
-    generated.into()
+    if decoder {
+        let arg_type_decoder = format_ident!("{}Decoder", type_to_ident(arg_type).unwrap());
+        code.extend(quote! {
+            impl <'a> From<#array_struct_name<'a>> for Vec<#arg_type_decoder<'a>> {
+                fn from(value: #array_struct_name<'a>) -> Self {
+                    value.into_iter().collect()
+                }
+            }
+
+            impl<'a> IntoIterator for #array_struct_name<'a> {
+                type Item = #arg_type_decoder<'a>;
+                type IntoIter = #iterator_name<'a>;
+                fn into_iter(self) -> Self::IntoIter {
+                    #iterator_name {
+                        scanner: sje::scanner::JsonScanner::wrap(self.bytes),
+                        remaining: self.remaining
+                    }
+                }
+            }
+            impl <'a> Iterator for #iterator_name<'a> {
+                type Item = #arg_type_decoder<'a>;
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    #next_impl
+                }
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (self.remaining, Some(self.remaining))
+                }
+            }
+            impl From<#array_struct_name<'_>> for Vec<#arg_type> {
+                fn from(value: #array_struct_name<'_>) -> Self {
+                    value.into_iter().map(|decoder| decoder.into()).collect()
+                }
+            }
+        });
+    } else {
+        code.extend(quote! {
+            impl From<#array_struct_name<'_>> for Vec<#arg_type> {
+                fn from(value: #array_struct_name) -> Self {
+                    value.into_iter().collect()
+                }
+            }
+
+            impl<'a> IntoIterator for #array_struct_name<'a> {
+                type Item = #arg_type;
+                type IntoIter = #iterator_name<'a>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    #iterator_name {
+                        scanner: sje::scanner::JsonScanner::wrap(self.bytes),
+                        remaining: self.remaining
+                    }
+                }
+            }
+
+            impl Iterator for #iterator_name<'_> {
+                type Item = #arg_type;
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    #next_impl
+                }
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (self.remaining, Some(self.remaining))
+                }
+            }
+        });
+    }
+
+    // fallible iterator over the same elements
+    let try_iterator_name = format_ident!("{}TryIter", iterator_name.to_string().trim_end_matches("Iter"));
+    let try_next_impl = iterator_next_impl(arg_type, sje_field, tolerant, true);
+    let item = match decoder {
+        true => {
+            let arg_type_decoder = format_ident!("{}Decoder", type_to_ident(arg_type).unwrap());
+            quote!(#arg_type_decoder<'a>)
+        }
+        false => quote!(#arg_type),
+    };
+    code.extend(quote! {
+        pub struct #try_iterator_name<'a> {
+            scanner: sje::scanner::JsonScanner<'a>,
+            remaining: usize,
+        }
+
+        impl<'a> #array_struct_name<'a> {
+            /// Iterate over the elements, yielding an error for the first malformed element and stopping after it.
+            #[inline]
+            pub const fn try_iter(&self) -> #try_iterator_name<'a> {
+                #try_iterator_name {
+                    scanner: sje::scanner::JsonScanner::wrap(self.bytes),
+                    remaining: self.remaining,
+                }
+            }
+        }
+
+        impl<'a> #try_iterator_name<'a> {
+            /// Yield the elements only, stopping at the first error which is kept by the returned iterator.
+            #[inline]
+            pub fn record_errors(self) -> sje::ErrorRecorder<Self> {
+                sje::ErrorRecorder::new(self)
+            }
+
+            #[inline]
+            fn try_next(&mut self) -> Result<Option<#item>, sje::error::Error> {
+                #try_next_impl
+            }
+        }
+
+        impl<'a> Iterator for #try_iterator_name<'a> {
+            type Item = Result<#item, sje::error::Error>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                match self.try_next() {
+                    Ok(item) => item.map(Ok),
+                    Err(err) => {
+                        self.remaining = 0;
+                        Some(Err(err))
+                    }
+                }
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(self.remaining))
+            }
+        }
+    });
+    code
+}
+
+/// Struct type of the field that is decoded with its own generated decoder.
+fn nested_type(field: &Field) -> Option<&Type> {
+    let ty = &field.ty;
+    match sje_field_attribute(field).decoder && vec_element_type(ty).is_none() && option_inner_type(ty).is_none() {
+        true => Some(&field.ty),
+        false => None,
+    }
 }
 
+/// Expression reading the field value at the current scanner position.
+fn field_value(
+    field: &Field,
+    sje_field: &SjeFieldAttribute,
+    known_len: Option<usize>,
+    tolerant: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_name = field.ident.as_ref().unwrap();
+    match nested_type(field) {
+        Some(nested_type) => {
+            let field_name_string = field_name.to_string();
+            let decoder = match type_to_ident(nested_type) {
+                Some(ident) => format_ident!("{}Decoder", ident),
+                None => return Err(Error::new_spanned(nested_type, "nested decoder must be a named struct")),
+            };
+            // the nested decoder can be either an object or a tuple
+            Ok(quote! {{
+                let (offset, len) = scanner.skip_value().ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
+                let bytes = unsafe { bytes.get_unchecked(offset..offset + len) };
+                (bytes, #decoder::decode(bytes)?)
+            }})
+        }
+        None => {
+            let type_str = resolve_type(&field.ty, sje_field.ty.clone())?;
+            Ok(read_value(field_name, type_str, known_len, tolerant))
+        }
+    }
+}
+
+/// Expression reading the value at the current scanner position into decoder field representation.
+fn read_value(
+    field_name: &Ident,
+    type_str: &str,
+    known_len: Option<usize>,
+    tolerant: bool,
+) -> proc_macro2::TokenStream {
+    let field_name_string = field_name.to_string();
+    // numbers and booleans end at the separator, which may be preceded by whitespace
+    let trim = match tolerant && matches!(type_str, "number" | "boolean") {
+        true => quote!(let (offset, len) = scanner.trim_end(offset, len);),
+        false => quote!(),
+    };
+    match (type_str, known_len) {
+        ("array", _) => quote! {{
+            let (offset, len, count) = scanner.next_array().ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
+            (unsafe { bytes.get_unchecked(offset..offset + len) }, count)
+        }},
+        (_, Some(known_len)) => {
+            let next = Ident::new(&format!("next_{}_with_known_len", type_str), field_name.span());
+            quote! {{
+                let (offset, len) = scanner.#next(#known_len).ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
+                sje::LazyField::from_bytes(unsafe { bytes.get_unchecked(offset..offset + len) })
+            }}
+        }
+        (_, None) => {
+            let next = Ident::new(&format!("next_{}", type_str), field_name.span());
+            quote! {{
+                let (offset, len) = scanner.#next().ok_or_else(|| sje::error::Error::MissingField(#field_name_string))?;
+                #trim
+                sje::LazyField::from_bytes(unsafe { bytes.get_unchecked(offset..offset + len) })
+            }}
+        }
+    }
+}
+
+/// Json type of the value, `ty_override` of an array applies to its elements.
 fn resolve_type(ty: &Type, ty_override: Option<String>) -> syn::Result<&'static str> {
+    if vec_element_type(ty).is_some() {
+        return Ok("array");
+    }
     if let Some(ty_override) = ty_override {
         return Ok(ty_override.leak());
     }
@@ -525,6 +1763,10 @@ fn resolve_type(ty: &Type, ty_override: Option<String>) -> syn::Result<&'static
                 "String" => Ok("string"),
                 "bool" => Ok("boolean"),
                 "Vec" => Ok("array"),
+                "Option" => match option_inner_type(ty) {
+                    Some(inner_type) if vec_element_type(inner_type).is_none() => resolve_type(inner_type, None),
+                    _ => Err(Error::new(Span::call_site(), "Option is only allowed for primitives and String")),
+                },
                 _ => Err(Error::new(Span::call_site(), "Only primitives, String, and Vec are allowed")),
             }
         }
@@ -532,7 +1774,84 @@ fn resolve_type(ty: &Type, ty_override: Option<String>) -> syn::Result<&'static
     }
 }
 
-fn iterator_next_impl(ty: &Type, decoder: bool) -> proc_macro2::TokenStream {
+/// Json type of each tuple element. The `ty` override is either a single type applied to every element or a comma
+/// separated list with one type per element, elements of user defined types are quoted unless overridden.
+/// The remainder element, when present, has no json type of its own and is excluded.
+fn tuple_element_types(
+    tuple: &TypeTuple,
+    ty_override: Option<String>,
+    remainder: bool,
+) -> syn::Result<Vec<&'static str>> {
+    let elements = match remainder {
+        true if tuple.elems.len() < 2 => {
+            return Err(Error::new_spanned(tuple, "remainder must follow at least one tuple element"));
+        }
+        true => tuple.elems.iter().take(tuple.elems.len() - 1).collect::<Vec<_>>(),
+        false => tuple.elems.iter().collect::<Vec<_>>(),
+    };
+    let overrides = match ty_override {
+        Some(ty_override) => {
+            let overrides = ty_override
+                .split(',')
+                .map(|ty| Some(ty.trim().to_string()))
+                .collect::<Vec<_>>();
+            match overrides.len() {
+                1 => vec![overrides[0].clone(); elements.len()],
+                len if len == elements.len() => overrides,
+                _ => return Err(Error::new_spanned(tuple, "expected one `ty` for each tuple element")),
+            }
+        }
+        None => vec![None; elements.len()],
+    };
+    elements
+        .into_iter()
+        .zip(overrides)
+        .map(|(ty, ty_override)| match (resolve_type(ty, ty_override.clone()), ty_override) {
+            (Ok(type_str @ ("string" | "number" | "boolean")), _) => Ok(type_str),
+            (_, None) => Ok("string"),
+            (_, Some(_)) => Err(Error::new_spanned(ty, "tuple element must be 'string', 'number' or 'boolean'")),
+        })
+        .collect()
+}
+
+/// Body of the iterator `next`. The fallible variant returns `Result<Option<T>, Error>` and reports malformed
+/// elements instead of stopping or panicking.
+fn iterator_next_impl(
+    ty: &Type,
+    sje_field: &SjeFieldAttribute,
+    tolerant: bool,
+    fallible: bool,
+) -> proc_macro2::TokenStream {
+    let decoder = sje_field.decoder;
+    let (none, missing) = match fallible {
+        true => (quote!(Ok(None)), quote!(.ok_or_else(|| sje::error::Error::other("malformed array element"))?)),
+        false => (quote!(None), quote!(?)),
+    };
+    let some = |value: proc_macro2::TokenStream| match fallible {
+        true => quote!(Ok(Some(#value))),
+        false => quote!(Some(#value)),
+    };
+    let parse = match fallible {
+        true => quote!(s.parse().map_err(|_| sje::error::Error::Parse(s.to_owned()))?),
+        false => quote!(s.parse().unwrap()),
+    };
+    let skip_separator = match tolerant {
+        true => quote!(self.scanner.next_element()#missing;),
+        false => quote!(self.scanner.skip(1);),
+    };
+    let skip_tuple_separator = match tolerant {
+        true => quote!(tuple_scanner.next_element()#missing;),
+        false => quote!(tuple_scanner.skip(1);),
+    };
+    // element missing from a malformed tuple would otherwise be read past the end of the tuple
+    let check_tuple_bounds = match fallible {
+        true => quote! {
+            if tuple_scanner.position() >= tuple_scanner.bytes().len() {
+                return Err(sje::error::Error::other("malformed array element"));
+            }
+        },
+        false => quote!(),
+    };
     match ty {
         Type::Path(path) => {
             let mut code = quote! {};
@@ -544,67 +1863,120 @@ fn iterator_next_impl(ty: &Type, decoder: bool) -> proc_macro2::TokenStream {
                     false => format_ident!("{}", last.ident.clone()),
                 };
 
-                let last = match decoder {
-                    true => quote! {
-                        Some(#ident::decode(bytes).unwrap())
-                    },
-                    false => quote! {
-                        let s = unsafe { std::str::from_utf8_unchecked(bytes) };
-                        Some(#ident::from_str(s).unwrap())
-                    },
+                // decoder elements can be either objects or tuples, anything else is read as json scalar
+                // with the type taken from the `ty` override or the element type, user types default to
+                // string as tuple elements do
+                let type_str = match resolve_type(ty, sje_field.ty.clone()) {
+                    _ if decoder => "object",
+                    Ok(type_str @ ("string" | "number" | "boolean")) => type_str,
+                    Ok(_) => "object",
+                    Err(_) => "string",
+                };
+                let next = match decoder {
+                    true => format_ident!("skip_value"),
+                    false => format_ident!("next_{}", type_str),
+                };
+                // numbers and booleans end at the separator, which may be preceded by whitespace
+                let trim = match tolerant && matches!(type_str, "number" | "boolean") {
+                    true => quote!(let (offset, len) = self.scanner.trim_end(offset, len);),
+                    false => quote!(),
+                };
+                let last = match (decoder, fallible) {
+                    (true, true) => some(quote!(#ident::decode(bytes)?)),
+                    (true, false) => some(quote!(#ident::decode(bytes).unwrap())),
+                    (false, _) => {
+                        let value = some(parse.clone());
+                        quote! {
+                            let s = unsafe { std::str::from_utf8_unchecked(bytes) };
+                            #value
+                        }
+                    }
                 };
 
                 code.extend(quote! {
-                    if self.scanner.position() + 1 == self.scanner.bytes().len() {
-                        return None;
+                    if self.remaining == 0 {
+                        return #none;
                     }
-                    self.scanner.skip(1);
-                    let (offset, len) = self.scanner.next_object()?;
+                    #skip_separator
+                    let (offset, len) = self.scanner.#next()#missing;
+                    #trim
                     self.remaining -= 1;
 
                     let bytes = &self.scanner.bytes()[offset..offset + len];
                     let bytes = unsafe { std::slice::from_raw_parts(bytes.as_ptr(), bytes.len()) };
                     #last
-                    // Some(#ident::decode(bytes).unwrap())
                 });
             }
             code
         }
         Type::Tuple(tuple) => {
+            let element_types = match tuple_element_types(tuple, sje_field.ty.clone(), sje_field.remainder) {
+                Ok(element_types) => element_types,
+                Err(e) => return e.to_compile_error(),
+            };
             // Generate code for processing each element
             let mut code = quote! {};
             let mut tuple_values = Vec::new();
 
             code.extend(quote! {
-                if self.scanner.position() + 1 == self.scanner.bytes().len() {
-                    return None;
+                if self.remaining == 0 {
+                    return #none;
                 }
-                self.scanner.skip(1);
-                let (offset, len) = self.scanner.next_tuple()?;
+                #skip_separator
+                let (offset, len) = self.scanner.next_tuple()#missing;
                 let mut tuple_scanner = unsafe { sje::scanner::JsonScanner::wrap(self.scanner.bytes().get_unchecked(offset..offset + len)) };
             });
 
             // Iterate over the tuple elements and generate code for each element
-            for (i, _) in tuple.elems.iter().enumerate() {
+            for (i, type_str) in element_types.into_iter().enumerate() {
                 // Dynamically generate a variable name based on the index
                 let var_name = format_ident!("val_{i}");
+                let next = format_ident!("next_{}", type_str);
+                let trim = match tolerant && matches!(type_str, "number" | "boolean") {
+                    true => quote!(let (offset, len) = tuple_scanner.trim_end(offset, len);),
+                    false => quote!(),
+                };
 
                 // Generate the code for processing this element
                 code.extend(quote! {
-                    tuple_scanner.skip(1);
-                    let (offset, len) = tuple_scanner.next_string()?;
-                    let str = unsafe { std::str::from_utf8_unchecked(tuple_scanner.bytes().get_unchecked(offset..offset + len)) };
-                    let #var_name = str.parse().unwrap();
+                    #skip_tuple_separator
+                    #check_tuple_bounds
+                    let (offset, len) = tuple_scanner.#next()#missing;
+                    #trim
+                    let s = unsafe { std::str::from_utf8_unchecked(tuple_scanner.bytes().get_unchecked(offset..offset + len)) };
+                    let #var_name = #parse;
                 });
 
                 // Add the variable to the tuple values vector for dynamic construction
                 tuple_values.push(quote! { #var_name });
             }
 
+            // any elements past the declared ones are ignored unless captured as the remainder
+            if sje_field.remainder {
+                let var_name = format_ident!("val_{}", tuple_values.len());
+                let rest = match fallible {
+                    true => quote! {
+                        tuple_scanner.bytes().get(tuple_scanner.position()..tuple_scanner.bytes().len() - 1)#missing
+                    },
+                    false => quote! {
+                        unsafe { tuple_scanner.bytes().get_unchecked(tuple_scanner.position()..tuple_scanner.bytes().len() - 1) }
+                    },
+                };
+                code.extend(quote! {
+                    let rest = #rest;
+                    let rest = rest.trim_ascii();
+                    let rest = rest.strip_prefix(b",").unwrap_or(rest).trim_ascii();
+                    let s = unsafe { std::str::from_utf8_unchecked(rest) };
+                    let #var_name = #parse;
+                });
+                tuple_values.push(quote! { #var_name });
+            }
+
             // Combine the generated code and the `Some(...)` expression
+            let value = some(quote!((#(#tuple_values),*)));
             code.extend(quote! {
                 self.remaining -= 1;
-                Some((#(#tuple_values),*))
+                #value
             });
 
             code
@@ -629,6 +2001,30 @@ fn is_integer_type(ty: &Type) -> bool {
     false
 }
 
+/// Extract `T` from `Vec<T>`.
+fn vec_element_type(ty: &Type) -> Option<&Type> {
+    generic_argument(ty, "Vec")
+}
+
+/// Extract `T` from `Option<T>`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    generic_argument(ty, "Option")
+}
+
+fn generic_argument<'t>(ty: &'t Type, wrapper: &str) -> Option<&'t Type> {
+    if let Type::Path(TypePath { qself: None, path }) = ty {
+        let segment = path.segments.last()?;
+        if segment.ident == wrapper {
+            if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(arg_type)) = args.args.first() {
+                    return Some(arg_type);
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Try to extract the bare `Ident` from a `&Type::Path`.
 fn type_to_ident(ty: &Type) -> Option<Ident> {
     if let Type::Path(TypePath { qself: None, path }) = ty {
@@ -679,10 +2075,14 @@ mod tests {
         check_type("bool", None, Ok("boolean"));
         check_type("String", Some("object"), Ok("object"));
         check_type("Vec<u8>", None, Ok("array"));
+        check_type("Vec<Price>", Some("string"), Ok("array"));
         check_type("Vec<Price>", None, Ok("array"));
         check_type("Vec<(Price, Quantity)>", None, Ok("array"));
         check_type("MyStruct", None, Err("Only primitives, String, and Vec are allowed"));
-        check_type("Option<u64>", None, Err("Only primitives, String, and Vec are allowed"));
+        check_type("Option<u64>", None, Ok("number"));
+        check_type("Option<String>", None, Ok("string"));
+        check_type("Option<u64>", Some("string"), Ok("string"));
+        check_type("Option<Vec<u64>>", None, Err("Option is only allowed for primitives and String"));
         check_type("Result<String, u8>", None, Err("Only primitives, String, and Vec are allowed"));
     }
 }